@@ -0,0 +1,100 @@
+//! Measures the time taken to parse forecasts, with and without the `*Error`
+//! confidence fields present.
+//!
+//! Run with and without the `compact` feature to compare the modes:
+//!
+//! ```sh
+//! cargo bench --bench parse
+//! cargo bench --bench parse --features compact
+//! ```
+
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::Forecast;
+use serde_json::Value;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 20_000;
+
+/// The measurements which the API may send an `*Error` field for.
+const MEASUREMENTS: &[&str] = &[
+	"cloudCover",
+	"dewPoint",
+	"humidity",
+	"ozone",
+	"precipAccumulation",
+	"precipIntensity",
+	"precipIntensityMax",
+	"precipProbability",
+	"pressure",
+	"temperature",
+	"temperatureMax",
+	"temperatureMin",
+	"visibility",
+	"windBearing",
+	"windSpeed",
+];
+
+/// Adds an `*Error` field alongside each measurement of each datapoint.
+fn with_errors(json: &str) -> String {
+	fn add(datapoint: &mut Value) {
+		if let Some(datapoint) = datapoint.as_object_mut() {
+			for measurement in MEASUREMENTS {
+				if datapoint.contains_key(*measurement) {
+					datapoint.insert(format!("{}Error", measurement), Value::from(0.25));
+				}
+			}
+		}
+	}
+
+	let mut forecast: Value = serde_json::from_str(json).unwrap();
+
+	add(&mut forecast["currently"]);
+
+	for block in &["minutely", "hourly", "daily"] {
+		if let Some(data) = forecast[*block]["data"].as_array_mut() {
+			data.iter_mut().for_each(add);
+		}
+	}
+
+	forecast.to_string()
+}
+
+fn parse(json: &str) -> usize {
+	let forecast: Forecast = serde_json::from_str(json).unwrap();
+
+	forecast.hourly.and_then(|hourly| hourly.data).map(|data| data.len()).unwrap_or(0)
+}
+
+fn bench(name: &str, json: &str) {
+	let mode = if cfg!(feature="compact") { "compact" } else { "full" };
+	let mut datapoints = 0;
+
+	for _ in 0..ITERATIONS / 10 {
+		datapoints += parse(json);
+	}
+
+	let start = Instant::now();
+
+	for _ in 0..ITERATIONS {
+		datapoints += parse(json);
+	}
+
+	let elapsed = start.elapsed();
+
+	println!(
+		"{} ({}): {:?} per forecast, over {} hourly datapoints",
+		name,
+		mode,
+		elapsed / ITERATIONS,
+		datapoints,
+	);
+}
+
+fn main() {
+	let json = include_str!("../tests/fixtures/forecast.json");
+
+	bench("parse", json);
+	bench("parse with errors", &with_errors(json));
+}
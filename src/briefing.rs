@@ -0,0 +1,170 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use models::{Alert, Forecast, TimeWindow};
+
+/// A builder for composing a [`DailyBriefing`] out of derived signals from a
+/// [`Forecast`], such as for a notification engine.
+///
+/// Each section is only computed when requested through its `with_*` method.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::{Briefing, Forecast};
+///
+/// fn notify(forecast: &Forecast) {
+///     let briefing = Briefing::from(forecast)
+///         .with_rain()
+///         .with_alerts()
+///         .with_temperature()
+///         .build();
+///
+///     if let Some(rain) = briefing.next_rain {
+///         println!("Rain expected from {} until {}", rain.start, rain.end);
+///     }
+/// }
+/// ```
+///
+/// [`DailyBriefing`]: struct.DailyBriefing.html
+/// [`Forecast`]: struct.Forecast.html
+#[derive(Clone, Debug)]
+pub struct Briefing<'a> {
+    forecast: &'a Forecast,
+    alerts: bool,
+    rain: bool,
+    rain_threshold: f64,
+    temperature: bool,
+}
+
+impl<'a> From<&'a Forecast> for Briefing<'a> {
+    fn from(forecast: &'a Forecast) -> Briefing<'a> {
+        Briefing {
+            forecast,
+            alerts: false,
+            rain: false,
+            rain_threshold: Briefing::DEFAULT_RAIN_THRESHOLD,
+            temperature: false,
+        }
+    }
+}
+
+impl<'a> Briefing<'a> {
+    /// The default [`precip_probability`] at or above which an hour is
+    /// considered rainy.
+    ///
+    /// [`precip_probability`]: struct.Datapoint.html#structfield.precip_probability
+    pub const DEFAULT_RAIN_THRESHOLD: f64 = 0.5;
+
+    /// Include the forecast's active alerts.
+    pub fn with_alerts(mut self) -> Self {
+        self.alerts = true;
+
+        self
+    }
+
+    /// Include the next window of rain, using the
+    /// [default threshold][`DEFAULT_RAIN_THRESHOLD`].
+    ///
+    /// [`DEFAULT_RAIN_THRESHOLD`]: #associatedconstant.DEFAULT_RAIN_THRESHOLD
+    pub fn with_rain(self) -> Self {
+        self.with_rain_threshold(Self::DEFAULT_RAIN_THRESHOLD)
+    }
+
+    /// Include the next window of rain, considering hours with a
+    /// [`precip_probability`] at or above the given threshold as rainy.
+    ///
+    /// [`precip_probability`]: struct.Datapoint.html#structfield.precip_probability
+    pub fn with_rain_threshold(mut self, threshold: f64) -> Self {
+        self.rain = true;
+        self.rain_threshold = threshold;
+
+        self
+    }
+
+    /// Include today's high and low temperatures.
+    pub fn with_temperature(mut self) -> Self {
+        self.temperature = true;
+
+        self
+    }
+
+    /// Compute the requested sections into a [`DailyBriefing`].
+    ///
+    /// [`DailyBriefing`]: struct.DailyBriefing.html
+    pub fn build(&self) -> DailyBriefing {
+        let mut briefing = DailyBriefing::default();
+
+        if self.alerts {
+            briefing.alerts = self.forecast.alerts.clone();
+        }
+
+        if self.rain {
+            briefing.next_rain = self.next_rain();
+        }
+
+        if self.temperature {
+            let today = self.forecast.daily.as_ref()
+                .and_then(|daily| daily.data.as_ref())
+                .and_then(|data| data.first());
+
+            if let Some(today) = today {
+                briefing.high = today.temperature_max;
+                briefing.low = today.temperature_min;
+            }
+        }
+
+        briefing
+    }
+
+    /// Finds the first consecutive run of rainy hours in the hourly block,
+    /// ending at the end of the last rainy hour.
+    fn next_rain(&self) -> Option<TimeWindow> {
+        let data = self.forecast.hourly.as_ref()?.data.as_ref()?;
+        let is_rainy = |probability: Option<f64>| match probability {
+            Some(probability) => probability >= self.rain_threshold,
+            None => false,
+        };
+
+        let start = data.iter().position(|datapoint| is_rainy(datapoint.precip_probability))?;
+        let end = data[start..].iter()
+            .take_while(|datapoint| is_rainy(datapoint.precip_probability))
+            .last()?;
+
+        Some(TimeWindow {
+            start: data[start].time,
+            end: end.time + 60 * 60,
+        })
+    }
+}
+
+/// A report of derived signals from a [`Forecast`], built by a [`Briefing`].
+///
+/// Sections which were not requested are left empty.
+///
+/// [`Briefing`]: struct.Briefing.html
+/// [`Forecast`]: struct.Forecast.html
+#[derive(Clone, Debug, Default)]
+pub struct DailyBriefing {
+    /// The active alerts.
+    pub alerts: Vec<Alert>,
+    /// Today's high temperature.
+    pub high: Option<f64>,
+    /// Today's low temperature.
+    pub low: Option<f64>,
+    /// The next window of hours in which rain is expected, if any.
+    pub next_rain: Option<TimeWindow>,
+}
@@ -0,0 +1,278 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Plain text rendering of a [`Forecast`] as a terminal weather card.
+//!
+//! [`Forecast`]: ../struct.Forecast.html
+
+use briefing::Briefing;
+use models::{CompassDirection, Forecast, Icon};
+use Unit;
+
+/// Five lines of ASCII art depicting the icon, each 11 characters wide.
+fn icon_art(icon: Option<Icon>) -> [&'static str; 5] {
+    use models::Icon::*;
+
+    match icon {
+        Some(ClearDay) => [
+            "   \\   /   ",
+            "    .-.    ",
+            " - (   ) - ",
+            "    `-'    ",
+            "   /   \\   ",
+        ],
+        Some(ClearNight) => [
+            "           ",
+            "    _.._   ",
+            "  .' .-'`  ",
+            "  |  (     ",
+            "   '._'-.  ",
+        ],
+        Some(Cloudy) => [
+            "           ",
+            "    .--.   ",
+            " .-(    ). ",
+            "(___.__)__)",
+            "           ",
+        ],
+        Some(Fog) => [
+            "           ",
+            " _ - _ - _ ",
+            "  _ - _ -  ",
+            " _ - _ - _ ",
+            "           ",
+        ],
+        Some(Hail) => [
+            "    .-.    ",
+            "   (   ).  ",
+            "  (___(__) ",
+            "   o o o   ",
+            "  o o o    ",
+        ],
+        Some(PartlyCloudyDay) => [
+            "  \\  /     ",
+            "_ /\"\".-.   ",
+            "  \\_(   ). ",
+            "  /(___(__)",
+            "           ",
+        ],
+        Some(PartlyCloudyNight) => [
+            "           ",
+            "   _  .-.  ",
+            "  ( .(   ).",
+            "   (___(__)",
+            "           ",
+        ],
+        Some(Rain) => [
+            "    .-.    ",
+            "   (   ).  ",
+            "  (___(__) ",
+            "   ' ' ' ' ",
+            "  ' ' ' '  ",
+        ],
+        Some(Sleet) => [
+            "    .-.    ",
+            "   (   ).  ",
+            "  (___(__) ",
+            "   ' * ' * ",
+            "  * ' * '  ",
+        ],
+        Some(Snow) => [
+            "    .-.    ",
+            "   (   ).  ",
+            "  (___(__) ",
+            "   *  *  * ",
+            "  *  *  *  ",
+        ],
+        Some(Thunderstorm) => [
+            "    .-.    ",
+            "   (   ).  ",
+            "  (___(__) ",
+            "   /_ /_   ",
+            "    /  /   ",
+        ],
+        Some(Tornado) => [
+            " _________ ",
+            "  \\_____/  ",
+            "   \\___/   ",
+            "    \\_/    ",
+            "     V     ",
+        ],
+        Some(Wind) => [
+            "           ",
+            " ~~~~ ~~~  ",
+            "  ~~~ ~~~~ ",
+            " ~~~~ ~~~  ",
+            "           ",
+        ],
+        None => ["           "; 5],
+    }
+}
+
+/// Formats a temperature to one decimal place, with the unit's symbol if it
+/// has one.
+fn temperature(value: f64, unit: Unit) -> String {
+    match unit.temperature_symbol() {
+        Some(symbol) => format!("{:.1} {}", value, symbol),
+        None => format!("{:.1}", value),
+    }
+}
+
+impl Forecast {
+    /// Renders the forecast as a multi-line, boxed plain text "weather card"
+    /// for display in a terminal.
+    ///
+    /// The card contains the location, ASCII art for the current
+    /// [`Icon`], the current conditions, today's high and low, and a hint for
+    /// when rain is next expected. Values are formatted with the symbols of
+    /// the given unit type, which should be the one the forecast was fetched
+    /// in. Absent fields are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Forecast, Unit};
+    ///
+    /// fn show(forecast: &Forecast) {
+    ///     println!("{}", forecast.render_card(Unit::Us));
+    /// }
+    /// ```
+    ///
+    /// [`Icon`]: enum.Icon.html
+    pub fn render_card(&self, unit: Unit) -> String {
+        let currently = self.currently.as_ref();
+        let today = self.daily.as_ref()
+            .and_then(|daily| daily.data.as_ref())
+            .and_then(|data| data.first());
+
+        let mut details = vec![];
+
+        let summary = currently.and_then(|currently| {
+            currently.summary.clone()
+                .or_else(|| currently.icon.map(|icon| icon.description().to_owned()))
+        });
+        details.extend(summary);
+
+        if let Some(currently) = currently {
+            match (currently.temperature, currently.apparent_temperature) {
+                (Some(actual), Some(apparent)) => details.push(format!(
+                    "{}, feels like {}",
+                    temperature(actual, unit),
+                    temperature(apparent, unit),
+                )),
+                (Some(actual), None) => details.push(temperature(actual, unit)),
+                _ => {},
+            }
+
+            if let Some(speed) = currently.wind_speed {
+                let mut wind = match unit.speed_symbol() {
+                    Some(symbol) => format!("Wind {:.1} {}", speed, symbol),
+                    None => format!("Wind {:.1}", speed),
+                };
+
+                if let Some(bearing) = currently.wind_bearing {
+                    wind.push(' ');
+                    wind.push_str(CompassDirection::from_bearing(bearing).abbreviation());
+                }
+
+                details.push(wind);
+            }
+        }
+
+        if let Some(today) = today {
+            match (today.temperature_max, today.temperature_min) {
+                (Some(high), Some(low)) => details.push(format!(
+                    "High {} / Low {}",
+                    temperature(high, unit),
+                    temperature(low, unit),
+                )),
+                (Some(high), None) => details.push(format!("High {}", temperature(high, unit))),
+                (None, Some(low)) => details.push(format!("Low {}", temperature(low, unit))),
+                (None, None) => {},
+            }
+        }
+
+        let mut lines = vec![
+            format!("{}, {} ({})", self.latitude, self.longitude, self.timezone),
+            String::new(),
+        ];
+
+        let art = icon_art(currently.and_then(|currently| currently.icon));
+
+        for (index, art) in art.iter().enumerate() {
+            let line = match details.get(index) {
+                Some(detail) => format!("{}  {}", art, detail),
+                None => art.to_string(),
+            };
+
+            lines.push(line.trim_end().to_owned());
+        }
+
+        while lines.last().map(String::is_empty) == Some(true) {
+            lines.pop();
+        }
+
+        if let Some(hint) = self.rain_hint() {
+            lines.push(String::new());
+            lines.push(hint);
+        }
+
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let border = format!("+{}+", "-".repeat(width + 2));
+
+        let mut card = border.clone();
+        card.push('\n');
+
+        for line in &lines {
+            let padding = width - line.chars().count();
+
+            card.push_str("| ");
+            card.push_str(line);
+            card.push_str(&" ".repeat(padding));
+            card.push_str(" |\n");
+        }
+
+        card.push_str(&border);
+
+        card
+    }
+
+    /// A hint for when rain is next expected according to the hourly block,
+    /// relative to the current datapoint, or the first hourly datapoint if it
+    /// is absent.
+    fn rain_hint(&self) -> Option<String> {
+        let hourly = self.hourly.as_ref()?.data.as_ref()?;
+        let now = match self.currently {
+            Some(ref currently) => currently.time,
+            None => hourly.first()?.time,
+        };
+
+        let window = match Briefing::from(self).with_rain().build().next_rain {
+            Some(window) => window,
+            None => return Some("No rain expected".to_owned()),
+        };
+
+        if window.start <= now {
+            return Some("Rain likely now".to_owned());
+        }
+
+        Some(match (window.start - now + 1800) / 3600 {
+            0 => "Rain likely within the hour".to_owned(),
+            1 => "Rain likely in about 1 hour".to_owned(),
+            hours => format!("Rain likely in about {} hours", hours),
+        })
+    }
+}
@@ -0,0 +1,420 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Custom deserialization functions used by the models via
+//! `#[serde(deserialize_with)]`.
+
+use models::Forecast;
+use serde::de::value::{Error as ValueError, StrDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::{Deserialize, Deserializer};
+use serde_json;
+use std::borrow::Cow;
+use std::io::Read;
+use std::result::Result as StdResult;
+use {Error, Result};
+
+#[cfg(any(feature="extra-fields", feature="raw-json"))]
+use serde_json::Value;
+#[cfg(feature="extra-fields")]
+use std::collections::BTreeMap;
+#[cfg(feature="serde_path_to_error")]
+use serde_path_to_error;
+#[cfg(feature="gzip")]
+use flate2::read::GzDecoder;
+
+#[cfg(feature="lenient-numbers")]
+pub use self::lenient::*;
+
+/// Deserializes a [`Forecast`] from a reader of JSON.
+///
+/// [`Forecast`]: ../struct.Forecast.html
+pub fn forecast_from_reader<R: Read>(reader: R) -> Result<Forecast> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+    let forecast = forecast_from_deserializer(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(forecast)
+}
+
+/// Deserializes a [`Forecast`] from a reader of JSON, along with the raw JSON.
+///
+/// [`Forecast`]: ../struct.Forecast.html
+#[cfg(feature="raw-json")]
+pub fn forecast_from_reader_with_raw<R: Read>(reader: R) -> Result<(Forecast, Value)> {
+    let value: Value = serde_json::from_reader(reader)?;
+    let forecast = forecast_from_deserializer(&value)?;
+
+    Ok((forecast, value))
+}
+
+/// Deserializes a [`Forecast`] from a JSON deserializer.
+///
+/// With the `serde_path_to_error` feature enabled, errors are reported as an
+/// [`Error::DecodePath`] including the path to the failing field.
+///
+/// [`Error::DecodePath`]: ../enum.Error.html#variant.DecodePath
+/// [`Forecast`]: ../struct.Forecast.html
+#[cfg(feature="serde_path_to_error")]
+fn forecast_from_deserializer<'de, D>(deserializer: D) -> Result<Forecast>
+    where D: Deserializer<'de, Error = serde_json::Error> {
+    serde_path_to_error::deserialize(deserializer).map_err(|why| {
+        Error::DecodePath {
+            path: why.path().to_string(),
+            message: why.inner().to_string(),
+        }
+    })
+}
+
+/// Deserializes a [`Forecast`] from a JSON deserializer.
+///
+/// [`Forecast`]: ../struct.Forecast.html
+#[cfg(not(feature="serde_path_to_error"))]
+fn forecast_from_deserializer<'de, D>(deserializer: D) -> Result<Forecast>
+    where D: Deserializer<'de, Error = serde_json::Error> {
+    Forecast::deserialize(deserializer).map_err(From::from)
+}
+
+/// The number of bytes of a response body kept in an
+/// [`Error::UnexpectedResponse`].
+///
+/// [`Error::UnexpectedResponse`]: ../enum.Error.html#variant.UnexpectedResponse
+pub const RESPONSE_SNIPPET_LEN: usize = 256;
+
+/// Deserializes a [`Forecast`] from the body of a successful response.
+///
+/// Returns an [`Error::UnexpectedResponse`] with the status code and the
+/// start of the body if it is not a forecast, so that the error shows what
+/// the server actually sent.
+///
+/// [`Error::UnexpectedResponse`]: ../enum.Error.html#variant.UnexpectedResponse
+/// [`Forecast`]: ../struct.Forecast.html
+pub fn forecast_from_response(status: u16, body: &[u8]) -> Result<Forecast> {
+    forecast_from_reader(body).map_err(|why| {
+        let snippet = &body[..body.len().min(RESPONSE_SNIPPET_LEN)];
+
+        Error::UnexpectedResponse {
+            body: String::from_utf8_lossy(snippet).into_owned(),
+            message: why.to_string(),
+            status,
+        }
+    })
+}
+
+/// Checks that a response's `Content-Type`, if present, is JSON, such as
+/// `application/json; charset=utf-8`.
+///
+/// Returns an [`Error::UnexpectedContentType`] otherwise, such as for an HTML
+/// maintenance page served by a proxy in place of the API's response.
+///
+/// [`Error::UnexpectedContentType`]: ../enum.Error.html#variant.UnexpectedContentType
+pub fn check_content_type(content_type: Option<&str>) -> Result<()> {
+    let content_type = match content_type {
+        Some(content_type) => content_type,
+        None => return Ok(()),
+    };
+
+    let essence = content_type.split(';').next().unwrap_or("").trim().to_lowercase();
+
+    if essence == "application/json" || essence.ends_with("+json") {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedContentType {
+            content_type: content_type.to_owned(),
+        })
+    }
+}
+
+/// The body of an error response from the API, such as
+/// `{"code":400,"error":"The given location is invalid."}`.
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    code: Option<u16>,
+    error: String,
+}
+
+/// Checks that a response's status code is successful.
+///
+/// Returns an [`Error::Api`] otherwise, with the code and message from the
+/// error object in the body, or the status code and the body as text if it
+/// is not an error object.
+///
+/// [`Error::Api`]: ../enum.Error.html#variant.Api
+pub fn check_status(status: u16, body: &[u8]) -> Result<()> {
+    if (200..300).contains(&status) {
+        return Ok(());
+    }
+
+    Err(match serde_json::from_slice::<ApiErrorBody>(body) {
+        Ok(body) => Error::Api {
+            code: body.code.unwrap_or(status),
+            message: body.error,
+        },
+        Err(_) => Error::Api {
+            code: status,
+            message: String::from_utf8_lossy(body).trim().to_owned(),
+        },
+    })
+}
+
+/// The name of the header stating how the body of a response is compressed.
+pub const ENCODING_HEADER: &str = "Content-Encoding";
+
+/// Decompresses the body of a response if its [`ENCODING_HEADER`] is `gzip`,
+/// returning other bodies unchanged.
+///
+/// Returns an [`Error::Io`] if the body is not valid gzip data.
+///
+/// [`ENCODING_HEADER`]: constant.ENCODING_HEADER.html
+/// [`Error::Io`]: ../enum.Error.html#variant.Io
+#[cfg(feature="gzip")]
+pub fn decode_body<'a>(encoding: Option<&str>, body: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+    match encoding {
+        Some(encoding) if encoding.trim().eq_ignore_ascii_case("gzip") => {
+            let mut decoded = Vec::new();
+            GzDecoder::new(body).read_to_end(&mut decoded)?;
+
+            Ok(Cow::Owned(decoded))
+        },
+        _ => Ok(Cow::Borrowed(body)),
+    }
+}
+
+/// Returns the body of a response unchanged, as compressed responses are
+/// not requested without the `gzip` feature.
+#[cfg(not(feature="gzip"))]
+pub fn decode_body<'a>(_: Option<&str>, body: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+    Ok(Cow::Borrowed(body))
+}
+
+/// The name of the header containing the number of calls made against the
+/// token today.
+pub const API_CALLS_HEADER: &str = "X-Forecast-API-Calls";
+
+/// The name of the header stating whether the response was served from the
+/// API's cache.
+pub const CACHE_HEADER: &str = "X-Forecast-Cache";
+
+/// The name of the header containing the time taken to produce the response.
+pub const RESPONSE_TIME_HEADER: &str = "X-Response-Time";
+
+/// Parses the value of an [`API_CALLS_HEADER`], such as `42`.
+///
+/// [`API_CALLS_HEADER`]: constant.API_CALLS_HEADER.html
+pub fn api_calls(header: &str) -> Option<u32> {
+    header.trim().parse().ok()
+}
+
+/// Parses the value of a [`CACHE_HEADER`], such as `HIT` or `MISS`.
+///
+/// [`CACHE_HEADER`]: constant.CACHE_HEADER.html
+pub fn cache_hit(header: &str) -> Option<bool> {
+    let header = header.trim();
+
+    if header.eq_ignore_ascii_case("hit") || header.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if header.eq_ignore_ascii_case("miss") || header.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parses the value of a [`RESPONSE_TIME_HEADER`], such as `55.112ms`, into
+/// a whole number of milliseconds.
+///
+/// [`RESPONSE_TIME_HEADER`]: constant.RESPONSE_TIME_HEADER.html
+pub fn response_time_ms(header: &str) -> Option<u32> {
+    let millis = header.trim().trim_end_matches("ms").trim().parse::<f64>().ok()?;
+
+    if millis.is_finite() && millis >= 0.0 && millis <= f64::from(u32::MAX) {
+        Some(millis.round() as u32)
+    } else {
+        None
+    }
+}
+
+/// Deserializes the fields of a [`Datapoint`] which are not otherwise known,
+/// for its [`extra`] field.
+///
+/// With the `compact` feature, the skipped `*Error` confidence fields are
+/// discarded rather than captured.
+///
+/// [`Datapoint`]: ../struct.Datapoint.html
+/// [`extra`]: ../struct.Datapoint.html#structfield.extra
+#[cfg(feature="extra-fields")]
+pub fn extra_fields<'de, D>(deserializer: D) -> StdResult<BTreeMap<String, Value>, D::Error>
+    where D: Deserializer<'de> {
+    let mut fields = BTreeMap::<String, Value>::deserialize(deserializer)?;
+
+    if cfg!(feature="compact") {
+        fields.retain(|name, _| !name.ends_with("Error"));
+    }
+
+    Ok(fields)
+}
+
+/// Parses a fieldless enum, such as a [`Unit`], from its name as sent to the
+/// API, compared case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownName`] with the `expected` description if the name
+/// is not recognized.
+///
+/// [`Error::UnknownName`]: ../enum.Error.html#variant.UnknownName
+/// [`Unit`]: ../enum.Unit.html
+pub fn from_name<T: DeserializeOwned>(name: &str, expected: &'static str) -> Result<T> {
+    let lowercase = name.to_ascii_lowercase();
+    let deserializer: StrDeserializer<ValueError> = lowercase.as_str().into_deserializer();
+
+    T::deserialize(deserializer).map_err(|_| Error::UnknownName {
+        expected,
+        name: name.to_owned(),
+    })
+}
+
+/// Deserializes either a single value or an array of values into a `Vec`.
+///
+/// Malformed responses occasionally send a single object where an array is
+/// expected, such as for [`Forecast::alerts`]. A `null` value produces an
+/// empty `Vec`.
+///
+/// [`Forecast::alerts`]: ../struct.Forecast.html#structfield.alerts
+pub fn one_or_many<'de, T, D>(deserializer: D) -> StdResult<Vec<T>, D::Error>
+    where T: Deserialize<'de>, D: Deserializer<'de> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(values)) => values,
+        Some(OneOrMany::One(value)) => vec![value],
+        None => vec![],
+    })
+}
+
+#[cfg(feature="lenient-numbers")]
+mod lenient {
+    use serde::de::{Deserializer, Error as DeError, Unexpected, Visitor};
+    use std::fmt::{Formatter, Result as FmtResult};
+    use std::result::Result as StdResult;
+
+    /// Accepts either a JSON number or a string containing a number, such as
+    /// `"72.4"`, as sent by some misbehaving proxies.
+    struct F64Visitor;
+
+    impl<'de> Visitor<'de> for F64Visitor {
+        type Value = f64;
+
+        fn expecting(&self, f: &mut Formatter) -> FmtResult {
+            f.write_str("a number or a numeric string")
+        }
+
+        fn visit_f64<E: DeError>(self, v: f64) -> StdResult<f64, E> {
+            Ok(v)
+        }
+
+        fn visit_i64<E: DeError>(self, v: i64) -> StdResult<f64, E> {
+            Ok(v as f64)
+        }
+
+        fn visit_u64<E: DeError>(self, v: u64) -> StdResult<f64, E> {
+            Ok(v as f64)
+        }
+
+        fn visit_str<E: DeError>(self, v: &str) -> StdResult<f64, E> {
+            v.trim().parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+
+    /// Accepts either a non-negative JSON integer or a string containing one.
+    struct U64Visitor;
+
+    impl<'de> Visitor<'de> for U64Visitor {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut Formatter) -> FmtResult {
+            f.write_str("an unsigned integer or an integer string")
+        }
+
+        fn visit_i64<E: DeError>(self, v: i64) -> StdResult<u64, E> {
+            if v < 0 {
+                return Err(E::invalid_value(Unexpected::Signed(v), &self));
+            }
+
+            Ok(v as u64)
+        }
+
+        fn visit_u64<E: DeError>(self, v: u64) -> StdResult<u64, E> {
+            Ok(v)
+        }
+
+        fn visit_str<E: DeError>(self, v: &str) -> StdResult<u64, E> {
+            v.trim().parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+
+    /// Wraps one of the above visitors, additionally accepting `null`.
+    struct OptionVisitor<V>(V);
+
+    impl<'de, V: Visitor<'de>> Visitor<'de> for OptionVisitor<V> {
+        type Value = Option<V::Value>;
+
+        fn expecting(&self, f: &mut Formatter) -> FmtResult {
+            self.0.expecting(f)?;
+
+            f.write_str(" or null")
+        }
+
+        fn visit_none<E: DeError>(self) -> StdResult<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: DeError>(self) -> StdResult<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> StdResult<Self::Value, D::Error>
+            where D: Deserializer<'de> {
+            deserializer.deserialize_any(self.0).map(Some)
+        }
+    }
+
+    pub fn f64<'de, D>(deserializer: D) -> StdResult<f64, D::Error>
+        where D: Deserializer<'de> {
+        deserializer.deserialize_any(F64Visitor)
+    }
+
+    pub fn option_f64<'de, D>(deserializer: D) -> StdResult<Option<f64>, D::Error>
+        where D: Deserializer<'de> {
+        deserializer.deserialize_option(OptionVisitor(F64Visitor))
+    }
+
+    pub fn u64<'de, D>(deserializer: D) -> StdResult<u64, D::Error>
+        where D: Deserializer<'de> {
+        deserializer.deserialize_any(U64Visitor)
+    }
+
+    pub fn option_u64<'de, D>(deserializer: D) -> StdResult<Option<u64>, D::Error>
+        where D: Deserializer<'de> {
+        deserializer.deserialize_option(OptionVisitor(U64Visitor))
+    }
+}
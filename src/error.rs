@@ -17,11 +17,18 @@
 use serde_json::{Error as JsonError, Value};
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Error as FmtError, Result as FmtResult};
-use std::io::Error as IoError;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 use std::result::Result as StdResult;
+use std::time::Duration;
 
+#[cfg(feature="http")]
+use http::Error as HttpError;
 #[cfg(feature="hyper")]
 use hyper::Error as HyperError;
+#[cfg(feature="reqwest")]
+use reqwest::Error as ReqwestError;
+#[cfg(feature="ureq")]
+use ureq::{Error as UreqError, ErrorKind as UreqErrorKind};
 
 /// A generic result type for all public-facing functions within the library.
 pub type Result<T> = StdResult<T, Error>;
@@ -32,11 +39,33 @@ pub type Result<T> = StdResult<T, Error>;
 /// [`Result`]: type.Result.html
 #[derive(Debug)]
 pub enum Error {
+	/// The API responded with an error, such as for an invalid token, an
+	/// invalid location, or an exceeded quota.
+	Api {
+		/// The HTTP status code of the error.
+		code: u16,
+		/// The message describing the error, such as
+		/// `"daily usage limit exceeded"`.
+		message: String,
+	},
 	/// A json decoding error, with a description and the value. This occurs
 	/// when the received value type is not of the expected type.
 	Decode(&'static str, Value),
+	/// A json decoding error, along with the path to the field that failed to
+	/// decode, such as `hourly.data[3].temperature`.
+	///
+	/// This is only produced with the `serde_path_to_error` feature enabled.
+	DecodePath {
+		/// The path to the field that failed to decode.
+		path: String,
+		/// A description of the error.
+		message: String,
+	},
 	/// A `std::fmt` error
 	Fmt(FmtError),
+	/// An `http` crate error
+	#[cfg(feature="http")]
+	Http(HttpError),
 	/// A `hyper` crate error
 	#[cfg(feature="hyper")]
 	Hyper(HyperError),
@@ -44,6 +73,214 @@ pub enum Error {
 	Json(JsonError),
 	/// A `std::io` module error
 	Io(IoError),
+	/// The latitude was outside of `[-90, 90]`, the longitude of a request was
+	/// outside of `[-180, 180]`, or the latitude or longitude was not a finite
+	/// number. Contains the given latitude and longitude.
+	InvalidLocation(f64, f64),
+	/// The options contradict each other, or the kind of request they were
+	/// given for, such that the API would silently ignore some of them.
+	/// Contains a description of the contradiction.
+	InvalidOptions(&'static str),
+	/// The API token was not a 32-character hexadecimal string, as checked
+	/// by [`validate_token`].
+	///
+	/// [`validate_token`]: fn.validate_token.html
+	MalformedToken,
+	/// The API token was empty, or contained only whitespace.
+	MissingToken,
+	/// A `reqwest` crate error
+	#[cfg(feature="reqwest")]
+	Reqwest(ReqwestError),
+	/// The forecast's current datapoint is older than the maximum age that
+	/// was allowed, such as when a caching proxy served outdated data.
+	StaleData {
+		/// How old the current datapoint is.
+		age: Duration,
+	},
+	/// A request did not complete within its timeout, such as one given to
+	/// [`DarkskyRequester::get_forecast_with_timeout`] or
+	/// [`UreqTransport::with_timeout`], or set on a hyper `Client` via
+	/// `Client::set_read_timeout`. Contains the timeout, if it is known.
+	///
+	/// [`DarkskyRequester::get_forecast_with_timeout`]: trait.DarkskyRequester.html#method.get_forecast_with_timeout
+	/// [`UreqTransport::with_timeout`]: struct.UreqTransport.html#method.with_timeout
+	Timeout(Option<Duration>),
+	/// A response with a successful status code was not JSON, such as an HTML
+	/// page served by a captive portal in place of the API's response.
+	///
+	/// Unsuccessful responses are reported as an [`Api`] error instead,
+	/// whatever their content type, so that an HTML maintenance page served
+	/// with a `503` code is [transient][`is_transient`].
+	///
+	/// [`Api`]: #variant.Api
+	/// [`is_transient`]: #method.is_transient
+	UnexpectedContentType {
+		/// The `Content-Type` of the response.
+		content_type: String,
+	},
+	/// A response with a successful status code was not a forecast, such as
+	/// an unrelated JSON document served by a misconfigured proxy.
+	UnexpectedResponse {
+		/// The start of the body, up to its first 256 bytes, with any invalid
+		/// UTF-8 replaced.
+		body: String,
+		/// The message of the error from decoding the body.
+		message: String,
+		/// The status code of the response.
+		status: u16,
+	},
+	/// A name could not be parsed, such as a unit type of `"metric"` passed to
+	/// [`Unit::from_str`].
+	///
+	/// [`Unit::from_str`]: enum.Unit.html#method.from_str
+	UnknownName {
+		/// What was expected, such as `"unit"`.
+		expected: &'static str,
+		/// The name which was not recognized.
+		name: String,
+	},
+	/// A `ureq` crate error
+	#[cfg(feature="ureq")]
+	Ureq(Box<UreqError>),
+}
+
+/// A stable, broad category of an [`Error`], for branching on the kind of
+/// failure without matching on individual variants, which may change.
+///
+/// Refer to [`Error::kind`] for how errors are categorized.
+///
+/// [`Error`]: enum.Error.html
+/// [`Error::kind`]: enum.Error.html#method.kind
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorKind {
+	/// The API token was missing or rejected.
+	Auth,
+	/// The response could not be decoded.
+	Decode,
+	/// A local IO operation failed, such as writing to disk.
+	Io,
+	/// The API could not be reached.
+	Network,
+	/// The token's request quota was exceeded.
+	Quota,
+	/// An operation timed out.
+	Timeout,
+	/// Any other error.
+	Other,
+}
+
+impl Error {
+	/// The broad [category][`ErrorKind`] of the error.
+	///
+	/// Errors are categorized as follows:
+	///
+	/// - [`Decode`], [`DecodePath`], [`UnexpectedResponse`], and non-IO
+	///   [`Json`] errors, as well as [`Reqwest`] errors from decoding the
+	///   response, are [`ErrorKind::Decode`];
+	/// - [`Hyper`], [`Ureq`], and other [`Reqwest`] errors, [`Json`] errors
+	///   from failing to read the response, and [`UnexpectedContentType`] are
+	///   [`ErrorKind::Network`];
+	/// - [`Io`] errors are [`ErrorKind::Io`];
+	/// - [`MalformedToken`] and [`MissingToken`], as well as [`Api`] errors
+	///   with a `401` or `403` code, are [`ErrorKind::Auth`], except for
+	///   `403` errors from exceeding the daily usage limit;
+	/// - [`Api`] errors with a `429` code or from exceeding the daily usage
+	///   limit are [`ErrorKind::Quota`];
+	/// - [`Timeout`] errors, [`Hyper`] and [`Io`] errors from an IO operation
+	///   which timed out, and [`Reqwest`] errors from a request which timed
+	///   out, are [`ErrorKind::Timeout`];
+	/// - all others are [`ErrorKind::Other`].
+	///
+	/// [`Api`]: #variant.Api
+	/// [`Decode`]: #variant.Decode
+	/// [`DecodePath`]: #variant.DecodePath
+	/// [`ErrorKind`]: enum.ErrorKind.html
+	/// [`ErrorKind::Auth`]: enum.ErrorKind.html#variant.Auth
+	/// [`ErrorKind::Decode`]: enum.ErrorKind.html#variant.Decode
+	/// [`ErrorKind::Io`]: enum.ErrorKind.html#variant.Io
+	/// [`ErrorKind::Network`]: enum.ErrorKind.html#variant.Network
+	/// [`ErrorKind::Other`]: enum.ErrorKind.html#variant.Other
+	/// [`ErrorKind::Quota`]: enum.ErrorKind.html#variant.Quota
+	/// [`ErrorKind::Timeout`]: enum.ErrorKind.html#variant.Timeout
+	/// [`Hyper`]: #variant.Hyper
+	/// [`Io`]: #variant.Io
+	/// [`Json`]: #variant.Json
+	/// [`MalformedToken`]: #variant.MalformedToken
+	/// [`MissingToken`]: #variant.MissingToken
+	/// [`Reqwest`]: #variant.Reqwest
+	/// [`Timeout`]: #variant.Timeout
+	/// [`UnexpectedContentType`]: #variant.UnexpectedContentType
+	/// [`UnexpectedResponse`]: #variant.UnexpectedResponse
+	/// [`Ureq`]: #variant.Ureq
+	pub fn kind(&self) -> ErrorKind {
+		fn io_kind(err: &IoError, otherwise: ErrorKind) -> ErrorKind {
+			match err.kind() {
+				IoErrorKind::TimedOut | IoErrorKind::WouldBlock => ErrorKind::Timeout,
+				_ => otherwise,
+			}
+		}
+
+		match *self {
+			Error::Api { code: 429, .. } => ErrorKind::Quota,
+			Error::Api { code: 403, ref message } if message.contains("limit") => ErrorKind::Quota,
+			Error::Api { code: 401, .. } | Error::Api { code: 403, .. } => ErrorKind::Auth,
+			Error::Decode(..) | Error::DecodePath { .. } => ErrorKind::Decode,
+			Error::UnexpectedResponse { .. } => ErrorKind::Decode,
+			#[cfg(feature="hyper")]
+			Error::Hyper(HyperError::Io(ref inner)) => io_kind(inner, ErrorKind::Network),
+			#[cfg(feature="hyper")]
+			Error::Hyper(_) => ErrorKind::Network,
+			Error::Io(ref inner) => io_kind(inner, ErrorKind::Io),
+			Error::Json(ref inner) if inner.is_io() => ErrorKind::Network,
+			Error::Json(_) => ErrorKind::Decode,
+			Error::MalformedToken | Error::MissingToken => ErrorKind::Auth,
+			#[cfg(feature="reqwest")]
+			Error::Reqwest(ref inner) if inner.is_timeout() => ErrorKind::Timeout,
+			#[cfg(feature="reqwest")]
+			Error::Reqwest(ref inner) if inner.is_decode() => ErrorKind::Decode,
+			#[cfg(feature="reqwest")]
+			Error::Reqwest(_) => ErrorKind::Network,
+			Error::Timeout(_) => ErrorKind::Timeout,
+			Error::UnexpectedContentType { .. } => ErrorKind::Network,
+			#[cfg(feature="ureq")]
+			Error::Ureq(_) => ErrorKind::Network,
+			_ => ErrorKind::Other,
+		}
+	}
+
+	/// Whether the error is likely to be temporary, such that retrying the
+	/// same request may succeed.
+	///
+	/// This is the case for [`Hyper`] and [`Reqwest`] errors other than
+	/// decoding errors, [`Json`] errors from failing to read the response,
+	/// [`Ureq`] errors from failing to connect or read the response,
+	/// [`Timeout`] errors, and [`Api`] errors with a `5xx` code. Errors from an
+	/// invalid request, such as a rejected token, are not transient.
+	///
+	/// [`Api`]: #variant.Api
+	/// [`Hyper`]: #variant.Hyper
+	/// [`Json`]: #variant.Json
+	/// [`Reqwest`]: #variant.Reqwest
+	/// [`Timeout`]: #variant.Timeout
+	/// [`Ureq`]: #variant.Ureq
+	pub fn is_transient(&self) -> bool {
+		match *self {
+			Error::Api { code, .. } => code >= 500,
+			#[cfg(feature="hyper")]
+			Error::Hyper(_) => true,
+			Error::Json(ref inner) => inner.is_io(),
+			#[cfg(feature="reqwest")]
+			Error::Reqwest(ref inner) => !inner.is_decode(),
+			Error::Timeout(_) => true,
+			#[cfg(feature="ureq")]
+			Error::Ureq(ref inner) => {
+				let kind = inner.kind();
+
+				kind == UreqErrorKind::Dns || kind == UreqErrorKind::ConnectionFailed || kind == UreqErrorKind::Io
+			},
+			_ => false,
+		}
+	}
 }
 
 impl From<FmtError> for Error {
@@ -52,10 +289,67 @@ impl From<FmtError> for Error {
 	}
 }
 
+#[cfg(feature="http")]
+impl From<HttpError> for Error {
+	fn from(err: HttpError) -> Error {
+		Error::Http(err)
+	}
+}
+
+/// Whether an IO error is from a read or write exceeding its timeout, which
+/// is reported as `WouldBlock` on some platforms.
+#[cfg(any(feature="hyper", feature="ureq"))]
+fn is_timeout(err: &IoError) -> bool {
+	let kind = err.kind();
+
+	kind == IoErrorKind::TimedOut || kind == IoErrorKind::WouldBlock
+}
+
+/// Converts an IO error from reading a response into an error, as a timeout
+/// if it is from a read exceeding the transport's timeout.
+///
+/// IO errors are otherwise converted into an [`Error::Io`], as local IO, such
+/// as writing an archived forecast, should not be reported as a timeout.
+///
+/// [`Error::Io`]: enum.Error.html#variant.Io
+#[cfg(any(feature="hyper", feature="ureq"))]
+pub fn from_transport_io(err: IoError) -> Error {
+	if is_timeout(&err) {
+		Error::Timeout(None)
+	} else {
+		Error::Io(err)
+	}
+}
+
 #[cfg(feature="hyper")]
 impl From<HyperError> for Error {
 	fn from(err: HyperError) -> Error {
-		Error::Hyper(err)
+		match err {
+			HyperError::Io(ref inner) if is_timeout(inner) => Error::Timeout(None),
+			err => Error::Hyper(err),
+		}
+	}
+}
+
+#[cfg(feature="reqwest")]
+impl From<ReqwestError> for Error {
+	fn from(err: ReqwestError) -> Error {
+		Error::Reqwest(err)
+	}
+}
+
+#[cfg(feature="ureq")]
+impl From<UreqError> for Error {
+	fn from(err: UreqError) -> Error {
+		let timed_out = err.kind() == UreqErrorKind::Io && err.source()
+			.and_then(|source| source.downcast_ref::<IoError>())
+			.is_some_and(is_timeout);
+
+		if timed_out {
+			Error::Timeout(None)
+		} else {
+			Error::Ureq(Box::new(err))
+		}
 	}
 }
 
@@ -73,19 +367,57 @@ impl From<JsonError> for Error {
 
 impl Display for Error {
 	fn fmt(&self, f: &mut Formatter) -> FmtResult {
-		f.write_str(self.description())
+		match *self {
+			Error::Api { code, ref message } => write!(f, "API error {}: {}", code, message),
+			Error::DecodePath { ref path, ref message } => write!(f, "{}: {}", path, message),
+			Error::InvalidLocation(latitude, longitude) => {
+				write!(f, "Invalid location: {}, {}", latitude, longitude)
+			},
+			Error::InvalidOptions(reason) => write!(f, "Invalid options: {}", reason),
+			Error::StaleData { age } => write!(f, "Forecast data is {} seconds old", age.as_secs()),
+			Error::Timeout(Some(timeout)) => {
+				write!(f, "The request did not complete within {} ms", timeout.as_millis())
+			},
+			Error::UnexpectedContentType { ref content_type } => {
+				write!(f, "Expected a JSON response, but received {}", content_type)
+			},
+			Error::UnexpectedResponse { ref body, ref message, status } => {
+				write!(f, "Unexpected response with status {} ({}): {}", status, message, body)
+			},
+			Error::UnknownName { expected, ref name } => write!(f, "Unknown {}: {:?}", expected, name),
+			#[cfg(feature="ureq")]
+			Error::Ureq(ref inner) => Display::fmt(inner, f),
+			_ => f.write_str(self.description()),
+		}
 	}
 }
 
 impl StdError for Error {
 	fn description(&self) -> &str {
 		match *self {
+			Error::Api { ref message, .. } => message,
 			Error::Decode(msg, _) => msg,
+			Error::DecodePath { ref message, .. } => message,
 			Error::Fmt(ref inner) => inner.description(),
+			#[cfg(feature="http")]
+			Error::Http(ref inner) => inner.description(),
 			#[cfg(feature="hyper")]
 			Error::Hyper(ref inner) => inner.description(),
 			Error::Json(ref inner) => inner.description(),
 			Error::Io(ref inner) => inner.description(),
+			Error::InvalidLocation(..) => "The latitude or longitude is out of range",
+			Error::InvalidOptions(reason) => reason,
+			Error::MalformedToken => "The API token is not a 32-character hexadecimal string",
+			Error::MissingToken => "No API token was given",
+			#[cfg(feature="reqwest")]
+			Error::Reqwest(ref inner) => inner.description(),
+			Error::StaleData { .. } => "The forecast data is older than the maximum age",
+			Error::Timeout(_) => "The request did not complete within the timeout",
+			Error::UnexpectedContentType { .. } => "The response was not JSON",
+			Error::UnexpectedResponse { ref message, .. } => message,
+			Error::UnknownName { .. } => "The name was not recognized",
+			#[cfg(feature="ureq")]
+			Error::Ureq(..) => "The request could not be sent",
 		}
 	}
 }
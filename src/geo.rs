@@ -0,0 +1,59 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Great-circle math on latitude/longitude pairs, in degrees.
+
+/// The mean radius of the Earth, in kilometers.
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// The number of kilometers in a statute mile.
+pub const KM_PER_MILE: f64 = 1.609344;
+
+/// Calculates the great-circle distance between two points in kilometers,
+/// using the haversine formula.
+pub fn haversine_km(lat1: f64, long1: f64, lat2: f64, long2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_long = (long2 - long1).to_radians();
+
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * (d_long / 2.0).sin().powi(2);
+
+    // Rounding can push `a` just past 1 for antipodal points, such as from
+    // pole to pole, where `asin` would produce NaN.
+    2.0 * EARTH_RADIUS_KM * a.min(1.0).sqrt().asin()
+}
+
+/// Calculates the initial bearing from the first point to the second, in
+/// degrees clockwise from true north within `[0, 360)`.
+///
+/// Every direction is due south from the North Pole and due north from the
+/// South Pole, where the formula is singular, so those are returned instead.
+pub fn initial_bearing(lat1: f64, long1: f64, lat2: f64, long2: f64) -> f64 {
+    if lat1 >= 90.0 {
+        return 180.0;
+    } else if lat1 <= -90.0 {
+        return 0.0;
+    }
+
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_long = (long2 - long1).to_radians();
+
+    let y = d_long.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_long.cos();
+
+    (y.atan2(x).to_degrees() + 360.0) % 360.0
+}
@@ -81,11 +81,98 @@
 //!
 //! ### Features
 //!
+//! **compact**: Skips deserializing the `*_error` confidence fields of
+//! [`Datapoint`]s, which are then always `None`, for applications which do not
+//! use them. The fields remain present, so the size of a `Datapoint` is
+//! unchanged, and the parsing speedup is small; run the `parse` benchmark with
+//! and without the feature to measure it for your payloads.
+//!
 //! **hyper**: Enables an implementation of [`DarkskyRequester`] on hyper's
-//! `Client` (enabled by default).
+//! `Client` (enabled by default). Each read and write of a request can be
+//! bounded with the client's `set_read_timeout` and `set_write_timeout`, with
+//! requests exceeding them failing with an [`Error::Timeout`]. A bound on the
+//! whole request, via [`get_forecast_with_timeout`], requires an
+//! [`HttpTransport`] such as [`UreqTransport`].
+//!
+//! With `default-features = false` and no other features, the crate provides
+//! only the models, [`Options`], URI building, and JSON parsing, such as via
+//! [`Forecast::from_json_str`], for use with a custom transport or offline
+//! data:
+//!
+//! ```toml
+//! [dependencies.darksky]
+//! default-features = false
+//! version = "0.6"
+//! ```
+//!
+//! **http**: Enables building requests and parsing responses as `http` crate
+//! types, via [`build_http_request`] and [`parse_http_response`], for use with
+//! any HTTP client.
+//!
+//! **reqwest**: Enables an implementation of [`DarkskyRequesterAsync`] on
+//! reqwest's asynchronous `Client`, for use on a tokio runtime without
+//! blocking.
+//!
+//! **ureq**: Enables [`UreqTransport`], a blocking requester over ureq, with
+//! TLS handled transparently by rustls. This is the simplest way to request
+//! forecasts from small tools, with far fewer dependencies than hyper.
+//!
+//! **gzip**: Requests gzip-compressed responses from hyper's `Client`,
+//! reducing the bandwidth used by large hourly and daily forecasts, and
+//! decompresses gzipped bodies returned by an [`HttpTransport`] or passed to
+//! [`parse_http_response`]. ureq requests and decompresses gzip responses
+//! itself. This feature does not affect [`DarkskyRequesterAsync`]; reqwest
+//! users must enable reqwest's own `gzip` feature to have compressed
+//! responses requested and decoded.
+//!
+//! **lenient-numbers**: Accepts numeric fields that were sent as strings, such
+//! as `"temperature": "72.4"`, which some API gateways and proxies produce.
+//! This is disabled by default to avoid masking genuine type errors.
+//!
+//! **serde_path_to_error**: Reports decoding errors with the path to the field
+//! that failed, such as `hourly.data[3].temperature`, as an
+//! [`Error::DecodePath`].
+//!
+//! **locale**: Enables localized formatting of values, such as
+//! [`Datapoint::format_temperature`].
+//!
+//! **raw-json**: Enables [`RawForecast`], which retains the raw JSON of a
+//! deserialized forecast for inspection, such as when investigating changes to
+//! the API's schema. This roughly doubles the memory used by each forecast.
+//!
+//! **extra-fields**: Captures fields sent by the API which the crate does not
+//! know of, such as newly added ones, in [`Forecast::extra`] and
+//! [`Datapoint::extra`], rather than discarding them. They are included when
+//! the forecast is serialized again. Without it, these fields are always
+//! empty.
+//!
+//! **chrono**: Enables accessors for timestamps as chrono `DateTime`s, such as
+//! [`Datapoint::time_utc`], alongside the raw Unix timestamps.
+//!
+//! **timezones**: Enables checks against the IANA timezone database, such as
+//! [`Forecast::offset_consistency`], and parsing the timezone via
+//! [`Forecast::timezone_tz`]. This implies the `chrono` feature.
 //!
 //! [`DarkskyRequester`]: trait.DarkskyRequester.html
+//! [`DarkskyRequesterAsync`]: trait.DarkskyRequesterAsync.html
+//! [`Datapoint`]: struct.Datapoint.html
+//! [`Datapoint::extra`]: struct.Datapoint.html#structfield.extra
+//! [`Datapoint::format_temperature`]: struct.Datapoint.html#method.format_temperature
+//! [`Datapoint::time_utc`]: struct.Datapoint.html#method.time_utc
+//! [`Error::DecodePath`]: enum.Error.html#variant.DecodePath
+//! [`Error::Timeout`]: enum.Error.html#variant.Timeout
 //! [`Forecast`]: struct.Forecast.html
+//! [`Forecast::extra`]: struct.Forecast.html#structfield.extra
+//! [`Forecast::from_json_str`]: struct.Forecast.html#method.from_json_str
+//! [`Forecast::offset_consistency`]: struct.Forecast.html#method.offset_consistency
+//! [`Forecast::timezone_tz`]: struct.Forecast.html#method.timezone_tz
+//! [`HttpTransport`]: trait.HttpTransport.html
+//! [`Options`]: struct.Options.html
+//! [`RawForecast`]: struct.RawForecast.html
+//! [`UreqTransport`]: struct.UreqTransport.html
+//! [`build_http_request`]: fn.build_http_request.html
+//! [`get_forecast_with_timeout`]: trait.DarkskyRequester.html#method.get_forecast_with_timeout
+//! [`parse_http_response`]: fn.parse_http_response.html
 //! [DarkSky]: https://darksky.net
 //! [change in name]: http://status.darksky.net/2016/09/20/forecast-api-is-now-dark-sky-api.html
 //! [crates.io]: https://crates.io
@@ -98,25 +185,87 @@
 extern crate serde;
 extern crate serde_json;
 
+#[cfg(feature="chrono")]
+extern crate chrono;
+#[cfg(feature="chrono-tz")]
+extern crate chrono_tz;
+#[cfg(feature="flate2")]
+extern crate flate2;
+#[cfg(feature="http")]
+extern crate http;
 #[cfg(feature="hyper")]
 extern crate hyper;
+#[cfg(feature="reqwest")]
+extern crate reqwest;
+#[cfg(feature="serde_path_to_error")]
+extern crate serde_path_to_error;
+#[cfg(feature="ureq")]
+extern crate ureq;
 
+pub mod limits;
+
+mod briefing;
+mod card;
+mod de;
 mod error;
+mod geo;
+mod meteorology;
 mod models;
+mod request;
+mod simulation;
+mod transport;
+mod trends;
 
-pub use error::{Error, Result};
+pub use briefing::{Briefing, DailyBriefing};
+pub use error::{Error, ErrorKind, Result};
+pub use meteorology::{heat_index, wind_chill};
 pub use models::*;
+pub use request::Request;
+pub use simulation::SimulatedTransport;
+pub use transport::{HttpTransport, TransportResponse};
+#[cfg(feature="ureq")]
+pub use transport::UreqTransport;
+pub use trends::{Trend, Trends};
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, Write as IoWrite};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use std::collections::HashMap;
+#[cfg(feature="reqwest")]
+use std::future::Future;
+#[cfg(feature="reqwest")]
+use std::pin::Pin;
 
 pub static API_URL: &'static str = "https://api.darksky.net";
 
+/// The delay before the first retry of [`get_forecast_with_retry`], which
+/// doubles before each subsequent retry.
+///
+/// [`get_forecast_with_retry`]: trait.DarkskyRequester.html#method.get_forecast_with_retry
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
 /// A block is a name of a [`Datablock`] returned from the API. This can be used
 /// to exclude datablocks from being returned from the API, to reduce bandwidth.
 ///
+/// [Alerts][`Block::Alerts`] and [flags][`Block::Flags`] are not datablocks,
+/// but can be excluded in the same way.
+///
+/// [`Block::Alerts`]: #variant.Alerts
+/// [`Block::Flags`]: #variant.Flags
 /// [`Datablock`]: struct.Datablock.html
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub enum Block {
+    /// The severe weather alerts. When excluded, [`Forecast::alerts`] is
+    /// empty.
+    ///
+    /// [`Forecast::alerts`]: struct.Forecast.html#structfield.alerts
+    #[serde(rename="alerts")]
+    Alerts,
     #[serde(rename="currently")]
     Currently,
     #[serde(rename="daily")]
@@ -130,10 +279,21 @@ pub enum Block {
 }
 
 impl Block {
+    /// Every block, in the order they are listed in an `exclude` parameter.
+    const ALL: &'static [Block] = &[
+        Block::Alerts,
+        Block::Currently,
+        Block::Daily,
+        Block::Flags,
+        Block::Hourly,
+        Block::Minutely,
+    ];
+
     fn name(&self) -> &str {
         use Block::*;
 
         match *self {
+            Alerts => "alerts",
             Currently => "currently",
             Daily => "daily",
             Flags => "flags",
@@ -143,12 +303,40 @@ impl Block {
     }
 }
 
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a block from its name as sent to the API, such as `"minutely"` for
+/// [`Block::Minutely`], compared case-insensitively. This is the inverse of the
+/// `Display` implementation.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownName`] if the name is not recognized.
+///
+/// [`Error::UnknownName`]: enum.Error.html#variant.UnknownName
+/// [`Block::Minutely`]: enum.Block.html#variant.Minutely
+impl FromStr for Block {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        de::from_name(name, "block")
+    }
+}
+
 /// The language to return from the API for the [`summary`] field.
 ///
 /// The language is automatically [English][`Language::En`], so specifying
 /// English is not required.
 ///
+/// Summaries which the API can not produce in the requested language fall
+/// back to English, as described by [`Options::language`].
+///
 /// [`Language::En`]: #variant.En
+/// [`Options::language`]: struct.Options.html#method.language
 /// [`summary`]: struct.Datapoint.html#structfield.summary
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub enum Language {
@@ -161,12 +349,32 @@ pub enum Language {
     /// Belarusian
     #[serde(rename="be")]
     Be,
+    /// Bulgarian
+    #[serde(rename="bg")]
+    Bg,
+    /// Bengali
+    #[serde(rename="bn")]
+    Bn,
     /// Bosnian
     #[serde(rename="bs")]
     Bs,
+    /// Catalan
+    ///
+    /// This is unrelated to [`Unit::Ca`], which is sent as `ca` for the
+    /// `units` parameter rather than the `lang` parameter.
+    ///
+    /// [`Unit::Ca`]: enum.Unit.html#variant.Ca
+    #[serde(rename="ca")]
+    Ca,
     /// Czech
     #[serde(rename="cs")]
     Cs,
+    /// Welsh
+    #[serde(rename="cy")]
+    Cy,
+    /// Danish
+    #[serde(rename="da")]
+    Da,
     /// German
     #[serde(rename="de")]
     De,
@@ -176,12 +384,27 @@ pub enum Language {
     /// English
     #[serde(rename="en")]
     En,
+    /// Esperanto
+    #[serde(rename="eo")]
+    Eo,
     /// Spanish
     #[serde(rename="es")]
     Es,
+    /// Estonian
+    #[serde(rename="et")]
+    Et,
+    /// Finnish
+    #[serde(rename="fi")]
+    Fi,
     /// French
     #[serde(rename="fr")]
     Fr,
+    /// Hebrew
+    #[serde(rename="he")]
+    He,
+    /// Hindi
+    #[serde(rename="hi")]
+    Hi,
     /// Croatian
     #[serde(rename="hr")]
     Hr,
@@ -197,33 +420,69 @@ pub enum Language {
     /// Icelandic
     #[serde(rename="is")]
     Is,
+    /// Japanese
+    #[serde(rename="ja")]
+    Ja,
+    /// Georgian
+    #[serde(rename="ka")]
+    Ka,
+    /// Kannada
+    #[serde(rename="kn")]
+    Kn,
+    /// Korean
+    #[serde(rename="ko")]
+    Ko,
     /// Cornish
     #[serde(rename="kw")]
     Kw,
+    /// Latvian
+    #[serde(rename="lv")]
+    Lv,
+    /// Malayalam
+    #[serde(rename="ml")]
+    Ml,
+    /// Marathi
+    #[serde(rename="mr")]
+    Mr,
     /// Norwegian Bokmål
     #[serde(rename="nb")]
     Nb,
     /// Dutch
     #[serde(rename="nl")]
     Nl,
+    /// Punjabi
+    #[serde(rename="pa")]
+    Pa,
     /// Polish
     #[serde(rename="pl")]
     Pl,
     /// Portuguese
     #[serde(rename="pt")]
     Pt,
+    /// Romanian
+    #[serde(rename="ro")]
+    Ro,
     /// Russian
     #[serde(rename="ru")]
     Ru,
     /// Slovak
     #[serde(rename="sk")]
     Sk,
+    /// Slovenian
+    #[serde(rename="sl")]
+    Sl,
     /// Serbian
     #[serde(rename="sr")]
     Sr,
     /// Swedish
     #[serde(rename="sv")]
     Sv,
+    /// Tamil
+    #[serde(rename="ta")]
+    Ta,
+    /// Telugu
+    #[serde(rename="te")]
+    Te,
     /// Tetum
     #[serde(rename="tet")]
     Tet,
@@ -233,6 +492,9 @@ pub enum Language {
     /// Ukrainian
     #[serde(rename="uk")]
     Uk,
+    /// Urdu
+    #[serde(rename="ur")]
+    Ur,
     /// Igpay Atinlay
     #[serde(rename="x-pig-latin")]
     XPigLatin,
@@ -252,30 +514,53 @@ impl Language {
             Ar => "ar",
             Az => "az",
             Be => "be",
+            Bg => "bg",
+            Bn => "bn",
             Bs => "bs",
+            Ca => "ca",
             Cs => "cs",
+            Cy => "cy",
+            Da => "da",
             De => "de",
             El => "el",
             En => "en",
+            Eo => "eo",
             Es => "es",
+            Et => "et",
+            Fi => "fi",
             Fr => "fr",
+            He => "he",
+            Hi => "hi",
             Hr => "hr",
             Hu => "hu",
             Id => "id",
             It => "it",
             Is => "is",
+            Ja => "ja",
+            Ka => "ka",
+            Kn => "kn",
+            Ko => "ko",
             Kw => "kw",
+            Lv => "lv",
+            Ml => "ml",
+            Mr => "mr",
             Nb => "nb",
             Nl => "nl",
+            Pa => "pa",
             Pl => "pl",
             Pt => "pt",
+            Ro => "ro",
             Ru => "ru",
             Sk => "sk",
+            Sl => "sl",
             Sr => "sr",
             Sv => "sv",
+            Ta => "ta",
+            Te => "te",
             Tet => "tet",
             Tr => "tr",
             Uk => "uk",
+            Ur => "ur",
             XPigLatin => "x-pig-latin",
             Zh => "zh",
             ZhTw => "zh-tw",
@@ -283,6 +568,30 @@ impl Language {
     }
 }
 
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a language from its name as sent to the API, such as `"es"` for
+/// [`Language::Es`], compared case-insensitively. This is the inverse of the
+/// `Display` implementation.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownName`] if the name is not recognized.
+///
+/// [`Error::UnknownName`]: enum.Error.html#variant.UnknownName
+/// [`Language::Es`]: enum.Language.html#variant.Es
+impl FromStr for Language {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        de::from_name(name, "language")
+    }
+}
+
 /// The type of units that the API should send back. `us` is the default value,
 /// and does not need to be specified in that case.
 ///
@@ -307,7 +616,7 @@ pub enum Unit {
     /// [`Unit::Si`]: #variant.Si
     #[serde(rename="ca")]
     Ca,
-    /// Imperial units (the default).
+    /// SI units.
     #[serde(rename="si")]
     Si,
     /// Same as [Si][`Unit::Si`], except that [`nearest_storm_distance`] and
@@ -319,7 +628,7 @@ pub enum Unit {
     /// [`Unit::Si`]: #variant.Si
     #[serde(rename="uk2")]
     Uk2,
-    /// SI units.
+    /// Imperial units (the default).
     #[serde(rename="us")]
     Us,
 }
@@ -336,6 +645,131 @@ impl Unit {
             Us => "us",
         }
     }
+
+    /// The symbol for temperatures returned in this unit type, such as `"°F"`
+    /// for [`Us`][`Unit::Us`].
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`], as the actual unit type is
+    /// not known until the response is received.
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`Unit::Us`]: #variant.Us
+    pub fn temperature_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca | Si | Uk2 => Some("°C"),
+            Us => Some("°F"),
+        }
+    }
+
+    /// The symbol for speeds, such as [`wind_speed`], returned in this unit
+    /// type.
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`wind_speed`]: struct.Datapoint.html#structfield.wind_speed
+    pub fn speed_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca => Some("km/h"),
+            Si => Some("m/s"),
+            Uk2 | Us => Some("mph"),
+        }
+    }
+
+    /// The symbol for distances, such as [`visibility`], returned in this
+    /// unit type.
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`visibility`]: struct.Datapoint.html#structfield.visibility
+    pub fn distance_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca | Si => Some("km"),
+            Uk2 | Us => Some("mi"),
+        }
+    }
+
+    /// The symbol for the [`pressure`] returned in this unit type.
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`pressure`]: struct.Datapoint.html#structfield.pressure
+    pub fn pressure_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca | Si | Uk2 => Some("hPa"),
+            Us => Some("mbar"),
+        }
+    }
+
+    /// The symbol for the [`precip_intensity`] returned in this unit type.
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`precip_intensity`]: struct.Datapoint.html#structfield.precip_intensity
+    pub fn precip_intensity_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca | Si | Uk2 => Some("mm/h"),
+            Us => Some("in/h"),
+        }
+    }
+
+    /// The symbol for the [`precip_accumulation`] returned in this unit type.
+    ///
+    /// Returns `None` for [`Auto`][`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: #variant.Auto
+    /// [`precip_accumulation`]: struct.Datapoint.html#structfield.precip_accumulation
+    pub fn precip_accumulation_symbol(&self) -> Option<&'static str> {
+        use Unit::*;
+
+        match *self {
+            Auto => None,
+            Ca | Si | Uk2 => Some("cm"),
+            Us => Some("in"),
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a unit from its name as sent to the API, such as `"si"` for
+/// [`Unit::Si`], compared case-insensitively. This is the inverse of the
+/// `Display` implementation.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownName`] if the name is not recognized.
+///
+/// [`Error::UnknownName`]: enum.Error.html#variant.UnknownName
+/// [`Unit::Si`]: enum.Unit.html#variant.Si
+impl FromStr for Unit {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        de::from_name(name, "unit")
+    }
 }
 
 /// Build a list of options to send in the request, including the type of
@@ -355,9 +789,20 @@ impl Unit {
 /// [`Unit`]: enum.Unit.html
 /// [`get_forecast_with_options`]: fn.get_forecast_with_options.html
 #[derive(Clone, Debug, Default)]
-pub struct Options(HashMap<&'static str, String>);
+pub struct Options(BTreeMap<&'static str, String>);
 
 impl Options {
+    /// Creates an empty set of options, for building up options
+    /// conditionally or storing them for reuse with
+    /// [`DarkskyRequester::get_forecast_with_opts`].
+    ///
+    /// This is the same as `Options::default()`.
+    ///
+    /// [`DarkskyRequester::get_forecast_with_opts`]: trait.DarkskyRequester.html#method.get_forecast_with_opts
+    pub fn new() -> Options {
+        Options::default()
+    }
+
     /// Set the list of [`Datablock`]s to exclude. For a full list of potential
     /// datablocks to exclude, refer to [`Block`].
     ///
@@ -373,6 +818,44 @@ impl Options {
         self
     }
 
+    /// Set the list of [`Datablock`]s to include, excluding every other
+    /// [`Block`]. This is the inverse of [`exclude`], and is useful for
+    /// requesting only the [current conditions][`Block::Currently`] to reduce
+    /// the size of the response.
+    ///
+    /// This sets the same query parameter as [`exclude`], so it replaces a
+    /// prior call to either, and is replaced by a later call to either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Block, Options};
+    ///
+    /// let options = Options::default().only(vec![Block::Currently]);
+    /// let uri = darksky::build_uri("token", 37.8267, -122.423, &options).unwrap();
+    ///
+    /// assert!(uri.ends_with("?exclude=alerts,daily,flags,hourly,minutely"));
+    /// ```
+    ///
+    /// [`Block`]: enum.Block.html
+    /// [`Block::Currently`]: enum.Block.html#variant.Currently
+    /// [`Datablock`]: struct.Datablock.html
+    /// [`exclude`]: #method.exclude
+    pub fn only(mut self, blocks: Vec<Block>) -> Self {
+        let excluded = Block::ALL.iter()
+            .filter(|block| !blocks.contains(block))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if excluded.is_empty() {
+            self.0.remove("exclude");
+
+            self
+        } else {
+            self.exclude(excluded)
+        }
+    }
+
     /// Extends the hourly [forecast][`Forecast`] to the full `7` days ahead,
     /// rather than only the first `2` days.
     ///
@@ -385,6 +868,11 @@ impl Options {
 
     /// Set the language of the [`summary`] provided.
     ///
+    /// The API falls back to English for summaries it can not produce in the
+    /// requested language, such as for some regions, without indicating so in
+    /// the response. Localization code should be prepared for a mix of the
+    /// requested language and English across the summaries of a forecast.
+    ///
     /// [`summary`]: struct.Datapoint.html#structfield.summary
     pub fn language(mut self, language: Language) -> Self {
         self.0.insert("lang", language.name().to_owned());
@@ -392,6 +880,36 @@ impl Options {
         self
     }
 
+    /// The [`Language`] requested for summaries, or [English][`Language::En`]
+    /// if none was set, as the API defaults to.
+    ///
+    /// A language set via [`custom`] which is not a known [`Language`] is
+    /// also reported as English.
+    ///
+    /// Summaries may still be in English when another language was requested;
+    /// refer to [`language`] for the API's fallback behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Language, Options};
+    ///
+    /// assert_eq!(Options::default().language_or_default(), Language::En);
+    ///
+    /// let options = Options::default().language(Language::De);
+    /// assert_eq!(options.language_or_default(), Language::De);
+    /// ```
+    ///
+    /// [`Language`]: enum.Language.html
+    /// [`Language::En`]: enum.Language.html#variant.En
+    /// [`custom`]: #method.custom
+    /// [`language`]: #method.language
+    pub fn language_or_default(&self) -> Language {
+        self.0.get("lang")
+            .and_then(|name| name.parse().ok())
+            .unwrap_or(Language::En)
+    }
+
     /// Sets the unit type returned from the API. Refer to the
     /// [DarkSky documentation][docs] or the [`Unit`] docs for more info.
     ///
@@ -402,169 +920,1556 @@ impl Options {
 
         self
     }
-}
 
-/// The trait for implementations to different DarkSky routes.
-pub trait DarkskyRequester {
-    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude.
+    /// Sets an arbitrary query parameter, such as one supported by the API
+    /// which does not yet have a method here.
     ///
-    /// # Examples
-    ///
-    /// ```rust,no_run
-    /// extern crate darksky;
-    /// extern crate hyper;
-    /// extern crate hyper_native_tls;
+    /// The value is percent-encoded when the URI is built, so it may contain
+    /// any characters.
     ///
-    /// # use std::error::Error;
-    /// #
-    /// use darksky::{DarkskyRequester, Block};
-    /// use hyper::net::HttpsConnector;
-    /// use hyper::Client;
-    /// use hyper_native_tls::NativeTlsClient;
-    /// use std::env;
+    /// # Examples
     ///
-    /// # fn try_main() -> Result<(), Box<Error>> {
-    /// let tc = NativeTlsClient::new()?;
-    /// let connector = HttpsConnector::new(tc);
-    /// let client = Client::with_connector(connector);
+    /// ```rust
+    /// use darksky::Options;
     ///
-    /// let token = env::var("FORECAST_TOKEN")?;
-    /// let lat = 37.8267;
-    /// let long = -122.423;
+    /// let options = Options::default().custom("lang", "x-pig latin");
+    /// let uri = darksky::build_uri("token", 37.8267, -122.423, &options).unwrap();
     ///
-    /// match client.get_forecast(&token, lat, long) {
-    ///     Ok(forecast) => println!("{:?}", forecast),
-    ///     Err(why) => println!("Error getting forecast: {:?}", why),
-    /// }
-    /// #     Ok(())
-    /// # }
-    /// #
-    /// # fn main() {
-    /// #     try_main().unwrap();
-    /// # }
+    /// assert!(uri.ends_with("?lang=x-pig%20latin"));
     /// ```
-    ///
-    /// [`Forecast`]: struct.Forecast.html
-    fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast>;
+    pub fn custom(mut self, key: &'static str, value: &str) -> Self {
+        self.0.insert(key, value.to_owned());
 
-    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude,
-    /// setting options where needed. For a full list of options, refer to the
-    /// documentation for the [`Options`] builder.
-    ///
-    /// # Examples
-    ///
-    /// Retrieve an extended forecast, excluding the
-    /// [minutely block][`Block::Minutely`].
-    ///
-    /// ```rust,no_run
-    /// extern crate darksky;
-    /// extern crate hyper;
-    /// extern crate hyper_native_tls;
+        self
+    }
+
+    /// Overlays another set of options onto these, such as per-request options
+    /// onto a set of defaults.
     ///
-    /// # use std::error::Error;
-    /// #
-    /// use darksky::{DarkskyRequester, Block};
-    /// use hyper::net::HttpsConnector;
-    /// use hyper::Client;
-    /// use hyper_native_tls::NativeTlsClient;
-    /// use std::env;
+    /// Options set in the overlay replace those set here, with the exception
+    /// of [excluded blocks][`exclude`], which are combined so that blocks
+    /// excluded by either are excluded.
     ///
-    /// # fn try_main() -> Result<(), Box<Error>> {
-    /// let tc = NativeTlsClient::new()?;
-    /// let connector = HttpsConnector::new(tc);
-    /// let client = Client::with_connector(connector);
+    /// # Examples
     ///
-    /// let token = env::var("FORECAST_TOKEN").expect("forecast token");
-    /// let lat = 37.8267;
-    /// let long = -122.423;
+    /// ```rust
+    /// use darksky::{Block, Options, Unit};
     ///
-    /// let req = client.get_forecast_with_options(&token, lat, long, |o| o
+    /// let defaults = Options::default()
     ///     .exclude(vec![Block::Minutely])
-    ///     .extend_hourly());
+    ///     .unit(Unit::Us);
+    /// let overlay = Options::default()
+    ///     .exclude(vec![Block::Flags])
+    ///     .unit(Unit::Si);
     ///
-    /// match req {
-    ///     Ok(forecast) => println!("{:?}", forecast),
-    ///     Err(why) => println!("Error getting forecast: {:?}", why),
-    /// }
-    /// #     Ok(())
-    /// # }
-    /// #
-    /// # fn main() {
-    /// #     try_main().unwrap();
-    /// # }
+    /// let options = defaults.merge(overlay);
+    /// let uri = darksky::build_uri("token", 37.8267, -122.423, &options).unwrap();
+    ///
+    /// assert!(uri.ends_with("?exclude=minutely,flags&units=si"));
     /// ```
     ///
-    /// [`Block::Minutely`]: enum.Block.html#variant.Minutely
-    /// [`Forecast`]: struct.Forecast.html
-    /// [`Options`]: struct.Options.html
-    fn get_forecast_with_options<F>(
-        &self,
-        token: &str,
-        latitude: f64,
-        longitude: f64,
-        options: F
-    ) -> Result<Forecast> where F: FnOnce(Options) -> Options;
-}
-
-#[cfg(feature="hyper")]
-mod hyper_support {
-    use hyper::client::{Client, Response};
-    use serde_json;
-    use std::collections::HashMap;
-    use std::fmt::Write;
-    use ::{API_URL, DarkskyRequester, Forecast, Options, Result};
-
-    impl DarkskyRequester for Client {
-        fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast> {
-            let uri = format!("{}/forecast/{}/{},{}?units=auto", API_URL, token, latitude, longitude);
+    /// [`exclude`]: #method.exclude
+    pub fn merge(mut self, overlay: Options) -> Self {
+        for (key, value) in overlay.0 {
+            let value = match self.0.get(key) {
+                Some(existing) if key == "exclude" => {
+                    let mut names = existing.split(',')
+                        .filter(|name| !name.is_empty())
+                        .collect::<Vec<_>>();
 
-            let response = self.get(&uri).send()?;
-
-            serde_json::from_reader::<Response, Forecast>(response).map_err(From::from)
-        }
-
-        fn get_forecast_with_options<F>(
-            &self,
-            token: &str,
-            latitude: f64,
-            longitude: f64,
-            options: F
-        ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
-            let options = options(Options(HashMap::new())).0;
-
-            let uri = {
-                let mut uri = String::new();
-                uri.push_str(API_URL);
-                uri.push_str("/forecast/");
-                uri.push_str(token);
-                uri.push('/');
-                write!(uri, "{}", latitude)?;
-                uri.push(',');
-                write!(uri, "{}", longitude)?;
-                uri.push('?');
-
-                for (k, v) in options {
-                    uri.push_str(k);
-                    uri.push('=');
-
-                    {
-                        let v_bytes = v.into_bytes();
-
-                        unsafe {
-                            let bytes = uri.as_mut_vec();
-                            bytes.extend(v_bytes);
+                    for name in value.split(',').filter(|name| !name.is_empty()) {
+                        if !names.contains(&name) {
+                            names.push(name);
                         }
                     }
 
-                    uri.push('&');
-                }
-
-                uri
+                    names.join(",")
+                },
+                _ => value,
             };
 
-            let response = self.get(&uri).send()?;
+            self.0.insert(key, value);
+        }
 
-            serde_json::from_reader::<Response, Forecast>(response).map_err(From::from)
+        self
+    }
+}
+
+/// Build the URI for a [forecast][`Forecast`] request for the given latitude
+/// and longitude, with the given options applied as query parameters.
+///
+/// This is used by the [`DarkskyRequester`] implementations, and is exposed for
+/// custom transports and for inspecting the requests that would be made.
+///
+/// Leading and trailing whitespace, such as a trailing newline from a token
+/// read from a file, is trimmed from the token.
+///
+/// Option values are percent-encoded, other than the commas separating lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::{Block, Error, Options};
+///
+/// let options = Options::default()
+///     .exclude(vec![Block::Hourly])
+///     .extend_hourly();
+///
+/// match darksky::build_uri("token", 37.8267, -122.423, &options) {
+///     Err(Error::InvalidOptions(_)) => {},
+///     other => panic!("expected invalid options, got {:?}", other),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::MissingToken`] if the token is empty after trimming.
+///
+/// Returns [`Error::InvalidLocation`] if the latitude is outside of
+/// `[-90, 90]` or the longitude is outside of `[-180, 180]`, rather than
+/// spending an API call on a request which would be rejected.
+///
+/// Returns [`Error::InvalidOptions`] if an
+/// [extended hourly][`Options::extend_hourly`] block is requested while the
+/// [hourly block][`Block::Hourly`] is excluded, as the extension would be
+/// silently ignored.
+///
+/// [`Block::Hourly`]: enum.Block.html#variant.Hourly
+/// [`DarkskyRequester`]: trait.DarkskyRequester.html
+/// [`Error::InvalidLocation`]: enum.Error.html#variant.InvalidLocation
+/// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+/// [`Error::MissingToken`]: enum.Error.html#variant.MissingToken
+/// [`Forecast`]: struct.Forecast.html
+/// [`Options::extend_hourly`]: struct.Options.html#method.extend_hourly
+pub fn build_uri(token: &str, latitude: f64, longitude: f64, options: &Options) -> Result<String> {
+    build_uri_at(token, latitude, longitude, None, options)
+}
+
+/// Build the URI for a [Time Machine][time machine] request for the weather at
+/// the given latitude and longitude at a point in time, as a Unix timestamp,
+/// with the given options applied as query parameters.
+///
+/// This is the same as the URI built by [`build_uri`], with the time appended
+/// to the coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::Options;
+///
+/// let uri = darksky::build_time_machine_uri("token", 37.8267, -122.423, 1509993277, &Options::default()).unwrap();
+///
+/// assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423,1509993277");
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`build_uri`].
+///
+/// Additionally returns [`Error::InvalidOptions`] if an
+/// [extended hourly][`Options::extend_hourly`] block is requested, as Time
+/// Machine responses always cover a single day and cannot be extended.
+///
+/// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+/// [`Options::extend_hourly`]: struct.Options.html#method.extend_hourly
+/// [`build_uri`]: fn.build_uri.html
+/// [time machine]: https://darksky.net/dev/docs#time-machine-request
+pub fn build_time_machine_uri(
+    token: &str,
+    latitude: f64,
+    longitude: f64,
+    time: u64,
+    options: &Options,
+) -> Result<String> {
+    build_uri_at(token, latitude, longitude, Some(time), options)
+}
+
+fn build_uri_at(
+    token: &str,
+    latitude: f64,
+    longitude: f64,
+    time: Option<u64>,
+    options: &Options,
+) -> Result<String> {
+    let token = token.trim();
+
+    if token.is_empty() {
+        return Err(Error::MissingToken);
+    }
+
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(Error::InvalidLocation(latitude, longitude));
+    }
+
+    check_options(options, time.is_some())?;
+
+    let mut uri = String::new();
+    uri.push_str(API_URL);
+    uri.push_str("/forecast/");
+    uri.push_str(token);
+    uri.push('/');
+    write!(uri, "{},{}", latitude, longitude)?;
+
+    if let Some(time) = time {
+        write!(uri, ",{}", time)?;
+    }
+
+    let mut separator = '?';
+
+    for (k, v) in &options.0 {
+        uri.push(separator);
+        push_encoded(&mut uri, k);
+        uri.push('=');
+        push_encoded(&mut uri, v);
+
+        separator = '&';
+    }
+
+    Ok(uri)
+}
+
+/// Checks that the options do not contradict each other, or the kind of
+/// request they are made with, which the API would otherwise silently ignore.
+fn check_options(options: &Options, time_machine: bool) -> Result<()> {
+    if !options.0.contains_key("extend") {
+        return Ok(());
+    }
+
+    if time_machine {
+        return Err(Error::InvalidOptions("Time Machine requests cannot extend the hourly block"));
+    }
+
+    let hourly_excluded = match options.0.get("exclude") {
+        Some(list) => list.split(',').any(|name| name == Block::Hourly.name()),
+        None => false,
+    };
+
+    if hourly_excluded {
+        return Err(Error::InvalidOptions("the hourly block cannot be both extended and excluded"));
+    }
+
+    Ok(())
+}
+
+/// Appends a query key or value to the URI, percent-encoding each byte of it
+/// other than unreserved characters and commas, which separate lists such as
+/// [excluded blocks][`Options::exclude`] and are valid in a query as-is.
+///
+/// [`Options::exclude`]: struct.Options.html#method.exclude
+fn push_encoded(uri: &mut String, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b',' => {
+                uri.push(byte as char);
+            },
+            _ => {
+                uri.push('%');
+                uri.push(HEX[usize::from(byte >> 4)] as char);
+                uri.push(HEX[usize::from(byte & 0xF)] as char);
+            },
+        }
+    }
+}
+
+/// The latitude of the fixed location requested by [`build_probe_uri`].
+///
+/// [`build_probe_uri`]: fn.build_probe_uri.html
+const PROBE_LATITUDE: f64 = 37.8267;
+
+/// The longitude of the fixed location requested by [`build_probe_uri`].
+///
+/// [`build_probe_uri`]: fn.build_probe_uri.html
+const PROBE_LONGITUDE: f64 = -122.423;
+
+/// Excludes every block, for a minimal response payload.
+fn probe_options(options: Options) -> Options {
+    options.exclude(Block::ALL.to_vec())
+}
+
+/// Build the URI of a cheap liveness probe, as requested by
+/// [`DarkskyRequester::healthcheck`].
+///
+/// This is a forecast request for a fixed location with every [`Block`]
+/// excluded, so the response payload is minimal. The API only supports `GET`
+/// requests, so this should be requested with `GET` rather than `HEAD`.
+///
+/// **Note**: Requesting the probe consumes one API call from the token's
+/// quota, just like any other forecast request.
+///
+/// # Examples
+///
+/// ```rust
+/// let uri = darksky::build_probe_uri("token").unwrap();
+///
+/// assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=alerts,currently,daily,flags,hourly,minutely");
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`build_uri`].
+///
+/// [`Block`]: enum.Block.html
+/// [`DarkskyRequester::healthcheck`]: trait.DarkskyRequester.html#method.healthcheck
+/// [`build_uri`]: fn.build_uri.html
+pub fn build_probe_uri(token: &str) -> Result<String> {
+    build_uri(token, PROBE_LATITUDE, PROBE_LONGITUDE, &probe_options(Options::default()))
+}
+
+/// Check that a token looks like a DarkSky API token, a 32-character
+/// hexadecimal string, ignoring surrounding whitespace.
+///
+/// This catches typos and truncated tokens before any request is made, rather
+/// than with a `403` from the API. The format is not documented as
+/// guaranteed, so the check is never made implicitly: call this where a clear
+/// early error is wanted, such as when reading configuration, and skip it to
+/// disable the check.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(darksky::validate_token("0123456789abcdef0123456789ABCDEF").is_ok());
+/// assert!(darksky::validate_token("0123456789abcdef").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::MissingToken`] if the token is empty after trimming, and
+/// [`Error::MalformedToken`] if it is not 32 hexadecimal characters.
+///
+/// [`Error::MalformedToken`]: enum.Error.html#variant.MalformedToken
+/// [`Error::MissingToken`]: enum.Error.html#variant.MissingToken
+pub fn validate_token(token: &str) -> Result<()> {
+    let token = token.trim();
+
+    if token.is_empty() {
+        return Err(Error::MissingToken);
+    }
+
+    if token.len() != 32 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::MalformedToken);
+    }
+
+    Ok(())
+}
+
+/// Build an `http` crate request for a [forecast][`Forecast`] for the given
+/// latitude and longitude, decoupled from any specific HTTP client.
+///
+/// The request is a `GET` of the URI produced by [`build_uri`]. Once sent, the
+/// response can be parsed with [`parse_http_response`].
+///
+/// This requires the `http` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::Options;
+///
+/// let request = darksky::build_http_request("token", 37.8267, -122.423, &Options::default()).unwrap();
+///
+/// assert_eq!(request.uri(), "https://api.darksky.net/forecast/token/37.8267,-122.423");
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`build_uri`], as well as an [`Error::Http`] if
+/// the request could not be built.
+///
+/// [`Error::Http`]: enum.Error.html#variant.Http
+/// [`Forecast`]: struct.Forecast.html
+/// [`build_uri`]: fn.build_uri.html
+/// [`parse_http_response`]: fn.parse_http_response.html
+#[cfg(feature="http")]
+pub fn build_http_request(
+    token: &str,
+    latitude: f64,
+    longitude: f64,
+    options: &Options,
+) -> Result<http::Request<()>> {
+    let uri = build_uri(token, latitude, longitude, options)?;
+
+    http::Request::get(uri).body(()).map_err(From::from)
+}
+
+/// Parse an `http` crate response, such as one to a request built by
+/// [`build_http_request`], into a [`Forecast`].
+///
+/// The body may be any type which can be viewed as bytes, such as `Vec<u8>`
+/// or `Bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::Api`] if the response has an unsuccessful status code,
+/// with the code and message of the API's error.
+///
+/// Returns [`Error::UnexpectedContentType`] if a successful response has a
+/// `Content-Type` other than JSON, such as an HTML error page served by a
+/// proxy, rather than attempting to parse it.
+///
+/// Returns [`Error::UnexpectedResponse`] if the body is not a forecast, with
+/// the status code and the start of the body.
+///
+/// This requires the `http` feature.
+///
+/// [`Error::Api`]: enum.Error.html#variant.Api
+/// [`Error::UnexpectedContentType`]: enum.Error.html#variant.UnexpectedContentType
+/// [`Error::UnexpectedResponse`]: enum.Error.html#variant.UnexpectedResponse
+/// [`Forecast`]: struct.Forecast.html
+/// [`build_http_request`]: fn.build_http_request.html
+#[cfg(feature="http")]
+pub fn parse_http_response<T: AsRef<[u8]>>(response: http::Response<T>) -> Result<Forecast> {
+    let encoding = response.headers()
+        .get(http::header::CONTENT_ENCODING)
+        .and_then(|header| header.to_str().ok());
+    let body = de::decode_body(encoding, response.body().as_ref())?;
+    de::check_status(response.status().as_u16(), &body)?;
+    let content_type = response.headers()
+        .get(http::header::CONTENT_TYPE)
+        .map(|header| String::from_utf8_lossy(header.as_bytes()).into_owned());
+    de::check_content_type(content_type.as_ref().map(|header| &header[..]))?;
+
+    de::forecast_from_response(response.status().as_u16(), &body)
+}
+
+/// Parse an `http` crate response into a [`Forecast`] like
+/// [`parse_http_response`], along with the number of API calls made, whether
+/// the response was cached, and the response time from its headers.
+///
+/// This requires the `http` feature.
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_http_response`].
+///
+/// [`Forecast`]: struct.Forecast.html
+/// [`parse_http_response`]: fn.parse_http_response.html
+#[cfg(feature="http")]
+pub fn parse_http_response_with_meta<T: AsRef<[u8]>>(
+    response: http::Response<T>,
+) -> Result<ForecastResponse> {
+    let api_calls = response.headers()
+        .get(de::API_CALLS_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .and_then(de::api_calls);
+    let cache_hit = response.headers()
+        .get(de::CACHE_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .and_then(de::cache_hit);
+    let response_time_ms = response.headers()
+        .get(de::RESPONSE_TIME_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .and_then(de::response_time_ms);
+
+    Ok(ForecastResponse {
+        api_calls,
+        cache_hit,
+        forecast: parse_http_response(response)?,
+        response_time_ms,
+    })
+}
+
+/// The trait for implementations to different DarkSky routes.
+pub trait DarkskyRequester {
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude.
+    ///
+    /// No options are sent, so the API's defaults apply: notably, values are
+    /// returned in [US units][`Unit::Us`]. To have units selected based on the
+    /// location, pass [`Unit::Auto`] to [`Options::unit`] via
+    /// [`get_forecast_with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::{DarkskyRequester, Block};
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let lat = 37.8267;
+    /// let long = -122.423;
+    ///
+    /// match client.get_forecast(&token, lat, long) {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`Options::unit`]: struct.Options.html#method.unit
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`Unit::Us`]: enum.Unit.html#variant.Us
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast>;
+
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude,
+    /// setting options where needed. For a full list of options, refer to the
+    /// documentation for the [`Options`] builder.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve an extended forecast, excluding the
+    /// [minutely block][`Block::Minutely`].
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::{DarkskyRequester, Block};
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN").expect("forecast token");
+    /// let lat = 37.8267;
+    /// let long = -122.423;
+    ///
+    /// let req = client.get_forecast_with_options(&token, lat, long, |o| o
+    ///     .exclude(vec![Block::Minutely])
+    ///     .extend_hourly());
+    ///
+    /// match req {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Block::Minutely`]: enum.Block.html#variant.Minutely
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`Options`]: struct.Options.html
+    fn get_forecast_with_options<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options;
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast_with_options`],
+    /// taking prebuilt [`Options`] rather than a closure.
+    ///
+    /// This is useful when options are built up across several branches, or
+    /// stored for reuse across requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use darksky::{Block, DarkskyRequester, Forecast, Options, Result, Unit};
+    ///
+    /// fn fetch<R: DarkskyRequester>(requester: &R, token: &str, metric: bool, brief: bool) -> Result<Forecast> {
+    ///     let mut options = Options::new();
+    ///
+    ///     if metric {
+    ///         options = options.unit(Unit::Si);
+    ///     }
+    ///
+    ///     if brief {
+    ///         options = options.exclude(vec![Block::Minutely, Block::Hourly]);
+    ///     }
+    ///
+    ///     requester.get_forecast_with_opts(token, 37.8267, -122.423, options)
+    /// }
+    /// ```
+    ///
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`Options`]: struct.Options.html
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    fn get_forecast_with_opts(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: Options
+    ) -> Result<Forecast> {
+        self.get_forecast_with_options(token, latitude, longitude, |_| options)
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast`], failing with
+    /// an [`Error::Timeout`] containing the timeout if the request, from
+    /// connecting through reading the whole response, does not complete
+    /// within it.
+    ///
+    /// This is intended for servers requesting forecasts synchronously while
+    /// handling their own requests, which need an upper bound on how long
+    /// each call can block.
+    ///
+    /// The request is sent with [`HttpTransport::get_with_timeout`] by
+    /// [`HttpTransport`]s, such as [`UreqTransport`], which bounds the whole
+    /// request.
+    ///
+    /// This is not supported by hyper's `Client`, whose timeouts can only be
+    /// changed through a mutable reference, and which bound each read and
+    /// write rather than the whole request. Set them on the client with its
+    /// `set_read_timeout` and `set_write_timeout` instead, or use a
+    /// [`UreqTransport`] when a bound on the whole request is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature="ureq")]
+    /// # fn try_main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use darksky::{DarkskyRequester, Error, UreqTransport};
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let timeout = Duration::from_secs(2);
+    ///
+    /// match UreqTransport::new().get_forecast_with_timeout(&token, 37.8267, -122.423, timeout) {
+    ///     Ok(forecast) => println!("{:?}", forecast.currently),
+    ///     Err(Error::Timeout(_)) => println!("The API took too long"),
+    ///     Err(why) => return Err(why.into()),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Timeout`] if the request did not complete within
+    /// the timeout, as well as the same errors as [`get_forecast`].
+    ///
+    /// The default implementation can not bound the request, so it validates
+    /// it with [`build_uri`] and then returns an [`Error::InvalidOptions`]
+    /// without sending it.
+    ///
+    /// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+    /// [`Error::Timeout`]: enum.Error.html#variant.Timeout
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`HttpTransport`]: trait.HttpTransport.html
+    /// [`HttpTransport::get_with_timeout`]: trait.HttpTransport.html#method.get_with_timeout
+    /// [`UreqTransport`]: struct.UreqTransport.html
+    /// [`build_uri`]: fn.build_uri.html
+    /// [`get_forecast`]: #tymethod.get_forecast
+    fn get_forecast_with_timeout(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        _timeout: Duration
+    ) -> Result<Forecast> {
+        build_uri(token, latitude, longitude, &Options::default())?;
+
+        Err(Error::InvalidOptions("timeouts are not supported by this requester"))
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast_with_options`],
+    /// calling `progress` with the total number of bytes received so far each
+    /// time a chunk of the response body is read.
+    ///
+    /// This is useful for displaying progress when downloading large extended
+    /// forecasts over slow connections. The body is buffered in full before
+    /// being parsed, so prefer the other methods when progress isn't needed.
+    ///
+    /// The default implementation can not see the response's body, so it
+    /// calls `progress` once, after retrieving the forecast via
+    /// [`get_forecast_with_opts`], with the length of the forecast serialized
+    /// as JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let lat = 37.8267;
+    /// let long = -122.423;
+    ///
+    /// let req = client.get_forecast_with_progress(&token, lat, long, |o| o
+    ///     .extend_hourly(), |bytes| println!("Received {} bytes", bytes));
+    ///
+    /// match req {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    /// [`get_forecast_with_opts`]: #method.get_forecast_with_opts
+    fn get_forecast_with_progress<F, P>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F,
+        mut progress: P
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options, P: FnMut(u64) {
+        let forecast = self.get_forecast_with_opts(token, latitude, longitude, options(Options::default()))?;
+        progress(serde_json::to_vec(&forecast).map(|json| json.len() as u64).unwrap_or(0));
+
+        Ok(forecast)
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast_with_options`],
+    /// along with metadata from the response's headers: the number of calls
+    /// made against the token today, whether the forecast was cached, and the
+    /// time taken by the API to respond.
+    ///
+    /// This is useful for monitoring the token's quota programmatically.
+    ///
+    /// The default implementation can not see the response's headers, so it
+    /// retrieves the forecast via [`get_forecast_with_opts`] and leaves the
+    /// metadata as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::limits::FREE_DAILY_CALL_LIMIT;
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    ///
+    /// let response = client.get_forecast_with_meta(&token, 37.8267, -122.423, |o| o)?;
+    ///
+    /// if let Some(calls) = response.api_calls {
+    ///     println!("{} of {} free calls used today", calls, FREE_DAILY_CALL_LIMIT);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    /// [`get_forecast_with_opts`]: #method.get_forecast_with_opts
+    fn get_forecast_with_meta<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F
+    ) -> Result<ForecastResponse> where F: FnOnce(Options) -> Options {
+        Ok(ForecastResponse {
+            api_calls: None,
+            cache_hit: None,
+            forecast: self.get_forecast_with_opts(token, latitude, longitude, options(Options::default()))?,
+            response_time_ms: None,
+        })
+    }
+
+    /// Retrieve the weather for the given latitude and longitude at a point in
+    /// time, as a Unix timestamp, using a [Time Machine][time machine] request.
+    ///
+    /// The time may be in the past, for observed conditions, or in the
+    /// future, for conditions forecast from historical averages. The
+    /// [`currently`] datapoint is for the given time, and the
+    /// [daily block][`daily`] covers the whole of that day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let lat = 37.8267;
+    /// let long = -122.423;
+    ///
+    /// match client.get_time_machine(&token, lat, long, 1509993277) {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`currently`]: struct.Forecast.html#structfield.currently
+    /// [`daily`]: struct.Forecast.html#structfield.daily
+    /// [time machine]: https://darksky.net/dev/docs#time-machine-request
+    fn get_time_machine(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        time: u64
+    ) -> Result<Forecast> {
+        self.get_time_machine_with_options(token, latitude, longitude, time, |options| options)
+    }
+
+    /// Retrieve the weather for the given latitude and longitude at a point in
+    /// time like [`get_time_machine`], setting options where needed. For a
+    /// full list of options, refer to the documentation for the [`Options`]
+    /// builder.
+    ///
+    /// # Errors
+    ///
+    /// The default implementation can not add the time to the request, so it
+    /// validates the request with [`build_time_machine_uri`] and then returns
+    /// an [`Error::InvalidOptions`]. Requesters supporting Time Machine
+    /// requests override it, as the hyper `Client` and [`HttpTransport`]s do.
+    ///
+    /// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+    /// [`HttpTransport`]: trait.HttpTransport.html
+    /// [`Options`]: struct.Options.html
+    /// [`build_time_machine_uri`]: fn.build_time_machine_uri.html
+    /// [`get_time_machine`]: #method.get_time_machine
+    fn get_time_machine_with_options<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        time: u64,
+        options: F
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+        build_time_machine_uri(token, latitude, longitude, time, &options(Options::default()))?;
+
+        Err(Error::InvalidOptions("time machine requests are not supported by this requester"))
+    }
+
+    /// Perform a cheap liveness check, confirming that the token is accepted
+    /// and that the API is reachable.
+    ///
+    /// This requests a forecast for a fixed location with every
+    /// [`Block`] excluded, keeping the response payload minimal, and discards
+    /// the result. The API only supports `GET` requests, so a `HEAD` request
+    /// can not be used instead. The same request can be built with
+    /// [`build_probe_uri`] for use by monitoring scripts.
+    ///
+    /// **Note**: This consumes one API call from the token's quota, just like
+    /// any other forecast request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    ///
+    /// if let Err(why) = client.healthcheck(&token) {
+    ///     println!("DarkSky is unavailable: {:?}", why);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get_forecast_with_options`], such as an
+    /// error when the token is rejected or the quota has been exceeded.
+    ///
+    /// [`Block`]: enum.Block.html
+    /// [`build_probe_uri`]: fn.build_probe_uri.html
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    fn healthcheck(&self, token: &str) -> Result<()> {
+        self.get_forecast_with_options(token, PROBE_LATITUDE, PROBE_LONGITUDE, probe_options)
+            .map(|_| ())
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast_with_options`],
+    /// rejecting it if its current datapoint is older than `max_data_age`.
+    ///
+    /// This is intended for displays of safety-critical information, such as
+    /// marine or aviation boards, where showing stale data served by a caching
+    /// proxy is worse than showing nothing. No check is made when
+    /// `max_data_age` is `None`. Refer to [`Forecast::ensure_fresh`] for
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let max_age = Some(Duration::from_secs(15 * 60));
+    ///
+    /// match client.get_forecast_with_max_age(&token, 37.8267, -122.423, max_age, |o| o) {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleData`] if the current datapoint is too old, as
+    /// well as the same errors as [`get_forecast_with_options`].
+    ///
+    /// [`Error::StaleData`]: enum.Error.html#variant.StaleData
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`Forecast::ensure_fresh`]: struct.Forecast.html#method.ensure_fresh
+    /// [`get_forecast_with_options`]: #tymethod.get_forecast_with_options
+    fn get_forecast_with_max_age<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        max_data_age: Option<Duration>,
+        options: F
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+        let forecast = self.get_forecast_with_options(token, latitude, longitude, options)?;
+
+        if let Some(max_data_age) = max_data_age {
+            forecast.ensure_fresh(max_data_age)?;
+        }
+
+        Ok(forecast)
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast`], retrying up
+    /// to `retries` times after [transient][`Error::is_transient`] failures,
+    /// with an exponential backoff starting from [`DEFAULT_RETRY_DELAY`].
+    ///
+    /// Refer to [`get_forecast_with_retry_delay`] for which failures are
+    /// retried.
+    ///
+    /// [`DEFAULT_RETRY_DELAY`]: constant.DEFAULT_RETRY_DELAY.html
+    /// [`Error::is_transient`]: enum.Error.html#method.is_transient
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`get_forecast`]: #tymethod.get_forecast
+    /// [`get_forecast_with_retry_delay`]: #method.get_forecast_with_retry_delay
+    fn get_forecast_with_retry(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        retries: u32
+    ) -> Result<Forecast> {
+        self.get_forecast_with_retry_delay(token, latitude, longitude, retries, DEFAULT_RETRY_DELAY)
+    }
+
+    /// Retrieve a [forecast][`Forecast`] like [`get_forecast`], retrying up
+    /// to `retries` times after [transient][`Error::is_transient`] failures,
+    /// such as dropped connections and `5xx` [`Error::Api`] responses.
+    ///
+    /// The thread sleeps for `base_delay` before the first retry, doubling
+    /// the delay before each subsequent retry. Other failures, such as a
+    /// rejected token or an invalid location, are returned immediately, as
+    /// retrying them would only spend more API calls.
+    ///
+    /// This is intended for unattended jobs, such as from a cron job.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let delay = Duration::from_secs(2);
+    ///
+    /// match client.get_forecast_with_retry_delay(&token, 37.8267, -122.423, 3, delay) {
+    ///     Ok(forecast) => println!("{:?}", forecast),
+    ///     Err(why) => println!("Error getting forecast: {:?}", why),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get_forecast`]. If every attempt fails,
+    /// the error of the last attempt is returned.
+    ///
+    /// [`Error::Api`]: enum.Error.html#variant.Api
+    /// [`Error::is_transient`]: enum.Error.html#method.is_transient
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`get_forecast`]: #tymethod.get_forecast
+    fn get_forecast_with_retry_delay(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        retries: u32,
+        base_delay: Duration
+    ) -> Result<Forecast> {
+        let mut delay = base_delay;
+        let mut attempt = 0;
+
+        loop {
+            match self.get_forecast(token, latitude, longitude) {
+                Err(ref why) if why.is_transient() && attempt < retries => {},
+                result => return result,
+            }
+
+            thread::sleep(delay);
+
+            delay = delay.checked_mul(2).unwrap_or(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude
+    /// and write it as JSON to a simple on-disk store, returning the path of
+    /// the written file.
+    ///
+    /// Forecasts are stored at `{dir}/{latitude}_{longitude}/{epoch}.json`,
+    /// where `epoch` is the number of seconds since the Unix epoch at which
+    /// the forecast was retrieved. Missing directories are created. An
+    /// existing archive is never overwritten, so archiving the same location
+    /// twice within a second fails.
+    ///
+    /// This is intended for archivers which periodically collect forecasts,
+    /// such as from a cron job.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate darksky;
+    /// extern crate hyper;
+    /// extern crate hyper_native_tls;
+    ///
+    /// # use std::error::Error;
+    /// #
+    /// use darksky::DarkskyRequester;
+    /// use hyper::net::HttpsConnector;
+    /// use hyper::Client;
+    /// use hyper_native_tls::NativeTlsClient;
+    /// use std::env;
+    ///
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let tc = NativeTlsClient::new()?;
+    /// let connector = HttpsConnector::new(tc);
+    /// let client = Client::with_connector(connector);
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    ///
+    /// let path = client.archive_forecast(&token, 37.8267, -122.423, "archive")?;
+    /// println!("Wrote forecast to {}", path.display());
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get_forecast`], as well as an
+    /// [`Error::Io`] if the directories or file could not be written, such as
+    /// one of kind `AlreadyExists` if the file already exists.
+    ///
+    /// [`Error::Io`]: enum.Error.html#variant.Io
+    /// [`Forecast`]: struct.Forecast.html
+    /// [`get_forecast`]: #tymethod.get_forecast
+    fn archive_forecast<P: AsRef<Path>>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        dir: P
+    ) -> Result<PathBuf> {
+        let forecast = self.get_forecast(token, latitude, longitude)?;
+        let epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+
+        let mut path = dir.as_ref().join(format!("{}_{}", latitude, longitude));
+        fs::create_dir_all(&path)?;
+        path.push(format!("{}.json", epoch));
+
+        let file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, &forecast)?;
+        writer.flush()?;
+
+        Ok(path)
+    }
+}
+
+/// A boxed future resolving to a [forecast][`Forecast`], as returned by the
+/// methods of [`DarkskyRequesterAsync`].
+///
+/// This requires the `reqwest` feature.
+///
+/// [`DarkskyRequesterAsync`]: trait.DarkskyRequesterAsync.html
+/// [`Forecast`]: struct.Forecast.html
+#[cfg(feature="reqwest")]
+pub type ForecastFuture = Pin<Box<dyn Future<Output = Result<Forecast>> + Send>>;
+
+/// The trait for non-blocking implementations to different DarkSky routes,
+/// mirroring [`DarkskyRequester`].
+///
+/// The methods return boxed futures rather than being `async fn`s, so that
+/// they can be declared on a trait. They are awaited the same way, such as
+/// with `client.get_forecast(&token, lat, long).await` on a tokio runtime.
+/// The URI is built when the method is called, so the future does not borrow
+/// the token.
+///
+/// This requires the `reqwest` feature, which implements the trait on
+/// reqwest's asynchronous `Client`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// extern crate darksky;
+/// extern crate reqwest;
+///
+/// use darksky::{Block, DarkskyRequesterAsync, ForecastFuture};
+/// use reqwest::Client;
+///
+/// fn request(client: &Client, token: &str) -> ForecastFuture {
+///     client.get_forecast_with_options(token, 37.8267, -122.423, |o| o
+///         .exclude(vec![Block::Minutely]))
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`DarkskyRequester`]: trait.DarkskyRequester.html
+#[cfg(feature="reqwest")]
+pub trait DarkskyRequesterAsync {
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude,
+    /// like [`DarkskyRequester::get_forecast`].
+    ///
+    /// [`DarkskyRequester::get_forecast`]: trait.DarkskyRequester.html#tymethod.get_forecast
+    /// [`Forecast`]: struct.Forecast.html
+    fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> ForecastFuture;
+
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude,
+    /// setting options where needed, like
+    /// [`DarkskyRequester::get_forecast_with_options`].
+    ///
+    /// [`DarkskyRequester::get_forecast_with_options`]: trait.DarkskyRequester.html#tymethod.get_forecast_with_options
+    /// [`Forecast`]: struct.Forecast.html
+    fn get_forecast_with_options<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F
+    ) -> ForecastFuture where F: FnOnce(Options) -> Options;
+
+    /// Retrieve a [forecast][`Forecast`] for the given latitude and longitude,
+    /// taking prebuilt options, like
+    /// [`DarkskyRequester::get_forecast_with_opts`].
+    ///
+    /// [`DarkskyRequester::get_forecast_with_opts`]: trait.DarkskyRequester.html#method.get_forecast_with_opts
+    /// [`Forecast`]: struct.Forecast.html
+    fn get_forecast_with_opts(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: Options
+    ) -> ForecastFuture {
+        self.get_forecast_with_options(token, latitude, longitude, |_| options)
+    }
+}
+
+#[cfg(feature="hyper")]
+mod hyper_support {
+    use de;
+    use error::from_transport_io;
+    use hyper::client::{Client, Response};
+    use hyper::header::ContentType;
+    #[cfg(feature="gzip")]
+    use hyper::header::{qitem, AcceptEncoding, Encoding};
+    use std::io::{ErrorKind, Read};
+    use std::str;
+    use ::{DarkskyRequester, Forecast, ForecastResponse, Options, Result};
+    use ::{build_time_machine_uri, build_uri};
+
+    /// Sends a `GET` request, checking that the response is successful and
+    /// JSON.
+    ///
+    /// With the `gzip` feature, a gzip-compressed response is requested.
+    fn send(client: &Client, uri: &str) -> Result<Response> {
+        let request = client.get(uri);
+        #[cfg(feature="gzip")]
+        let request = request.header(AcceptEncoding(vec![qitem(Encoding::Gzip)]));
+        let mut response = request.send()?;
+
+        if !response.status.is_success() {
+            let mut body = Vec::new();
+            response.read_to_end(&mut body).map_err(from_transport_io)?;
+            let body = de::decode_body(header(&response, de::ENCODING_HEADER), &body)?;
+            de::check_status(response.status.to_u16(), &body)?;
+        }
+
+        let content_type = response.headers.get::<ContentType>().map(|header| header.to_string());
+        de::check_content_type(content_type.as_ref().map(|header| &header[..]))?;
+
+        Ok(response)
+    }
+
+    /// Parses the body of a successful response into a forecast,
+    /// decompressing it first if needed.
+    fn parse(response: &Response, body: &[u8]) -> Result<Forecast> {
+        let body = de::decode_body(header(response, de::ENCODING_HEADER), body)?;
+
+        de::forecast_from_response(response.status.to_u16(), &body)
+    }
+
+    /// Reads the body of a successful response and parses it into a forecast.
+    fn forecast(mut response: Response) -> Result<Forecast> {
+        let mut body = Vec::new();
+        response.read_to_end(&mut body).map_err(from_transport_io)?;
+
+        parse(&response, &body)
+    }
+
+    /// Retrieves the first value of a header, if it is valid UTF-8.
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        let values = response.headers.get_raw(name)?;
+
+        str::from_utf8(values.first()?).ok()
+    }
+
+    impl DarkskyRequester for Client {
+        fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast> {
+            let uri = build_uri(token, latitude, longitude, &Options::default())?;
+
+            forecast(send(self, &uri)?)
+        }
+
+        fn get_forecast_with_options<F>(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: F
+        ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+            self.get_forecast_with_opts(token, latitude, longitude, options(Options::default()))
+        }
+
+        fn get_forecast_with_opts(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: Options
+        ) -> Result<Forecast> {
+            let uri = build_uri(token, latitude, longitude, &options)?;
+
+            forecast(send(self, &uri)?)
+        }
+
+        fn get_forecast_with_progress<F, P>(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: F,
+            mut progress: P
+        ) -> Result<Forecast> where F: FnOnce(Options) -> Options, P: FnMut(u64) {
+            let options = options(Options::default());
+            let uri = build_uri(token, latitude, longitude, &options)?;
+
+            let mut response = send(self, &uri)?;
+            let mut body = Vec::new();
+            let mut chunk = [0; 8192];
+
+            loop {
+                let read = match response.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(ref why) if why.kind() == ErrorKind::Interrupted => continue,
+                    Err(why) => return Err(from_transport_io(why)),
+                };
+
+                body.extend_from_slice(&chunk[..read]);
+                progress(body.len() as u64);
+            }
+
+            parse(&response, &body)
+        }
+
+        fn get_forecast_with_meta<F>(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: F
+        ) -> Result<ForecastResponse> where F: FnOnce(Options) -> Options {
+            let options = options(Options::default());
+            let uri = build_uri(token, latitude, longitude, &options)?;
+
+            let response = send(self, &uri)?;
+            let api_calls = header(&response, de::API_CALLS_HEADER).and_then(de::api_calls);
+            let cache_hit = header(&response, de::CACHE_HEADER).and_then(de::cache_hit);
+            let response_time_ms = header(&response, de::RESPONSE_TIME_HEADER)
+                .and_then(de::response_time_ms);
+
+            Ok(ForecastResponse {
+                api_calls,
+                cache_hit,
+                forecast: forecast(response)?,
+                response_time_ms,
+            })
+        }
+
+        fn get_time_machine(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            time: u64
+        ) -> Result<Forecast> {
+            let uri = build_time_machine_uri(token, latitude, longitude, time, &Options::default())?;
+
+            forecast(send(self, &uri)?)
+        }
+
+        fn get_time_machine_with_options<F>(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            time: u64,
+            options: F
+        ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+            let options = options(Options::default());
+            let uri = build_time_machine_uri(token, latitude, longitude, time, &options)?;
+
+            forecast(send(self, &uri)?)
+        }
+    }
+}
+
+#[cfg(feature="reqwest")]
+mod reqwest_support {
+    use de;
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::{self, Client, Response};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use ::{DarkskyRequesterAsync, Error, Forecast, ForecastFuture, Options, Result, build_uri};
+
+    type Pending<T> = Pin<Box<dyn Future<Output = reqwest::Result<T>> + Send>>;
+
+    /// A request for a forecast, in each of its stages.
+    enum Request {
+        /// Waiting for the response's headers.
+        Sending(Pending<Response>),
+        /// Reading the response's body, with its status code.
+        Reading(Pending<String>, u16),
+        /// Failed before being sent, such as due to a missing token.
+        Failed(Option<Error>),
+    }
+
+    impl Request {
+        fn start(client: &Client, uri: Result<String>) -> ForecastFuture {
+            Box::pin(match uri {
+                Ok(uri) => Request::Sending(Box::pin(client.get(&uri).send())),
+                Err(why) => Request::Failed(Some(why)),
+            })
+        }
+    }
+
+    impl Future for Request {
+        type Output = Result<Forecast>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<Forecast>> {
+            loop {
+                let next = match *self {
+                    Request::Sending(ref mut pending) => match pending.as_mut().poll(cx) {
+                        Poll::Ready(Ok(response)) => {
+                            let status = response.status().as_u16();
+                            let content_type = response.headers()
+                                .get(CONTENT_TYPE)
+                                .map(|header| String::from_utf8_lossy(header.as_bytes()).into_owned());
+
+                            // Unsuccessful responses are reported from their
+                            // body once read, whatever their content type.
+                            if response.status().is_success() {
+                                if let Err(why) = de::check_content_type(content_type.as_ref().map(|header| &header[..])) {
+                                    return Poll::Ready(Err(why));
+                                }
+                            }
+
+                            Request::Reading(Box::pin(response.text()), status)
+                        },
+                        Poll::Ready(Err(why)) => return Poll::Ready(Err(why.into())),
+                        Poll::Pending => return Poll::Pending,
+                    },
+                    Request::Reading(ref mut pending, status) => return match pending.as_mut().poll(cx) {
+                        Poll::Ready(Ok(body)) => Poll::Ready(de::check_status(status, body.as_bytes())
+                            .and_then(|()| de::forecast_from_response(status, body.as_bytes()))),
+                        Poll::Ready(Err(why)) => Poll::Ready(Err(why.into())),
+                        Poll::Pending => Poll::Pending,
+                    },
+                    Request::Failed(ref mut why) => {
+                        let why = why.take().expect("request polled after completion");
+
+                        return Poll::Ready(Err(why));
+                    },
+                };
+
+                *self = next;
+            }
+        }
+    }
+
+    impl DarkskyRequesterAsync for Client {
+        fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> ForecastFuture {
+            Request::start(self, build_uri(token, latitude, longitude, &Options::default()))
+        }
+
+        fn get_forecast_with_options<F>(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: F
+        ) -> ForecastFuture where F: FnOnce(Options) -> Options {
+            self.get_forecast_with_opts(token, latitude, longitude, options(Options::default()))
+        }
+
+        fn get_forecast_with_opts(
+            &self,
+            token: &str,
+            latitude: f64,
+            longitude: f64,
+            options: Options
+        ) -> ForecastFuture {
+            Request::start(self, build_uri(token, latitude, longitude, &options))
         }
     }
 }
@@ -0,0 +1,61 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Limits documented by the DarkSky API, for referencing by name rather than
+//! by hardcoded numbers.
+//!
+//! Refer to the [official documentation][docs] for the latest values.
+//!
+//! [docs]: https://darksky.net/dev/docs
+
+/// The number of API calls per day which are free of charge.
+pub const FREE_DAILY_CALL_LIMIT: u32 = 1000;
+
+/// The number of days ahead covered by the [daily block][`Forecast::daily`],
+/// not including today.
+///
+/// [`Forecast::daily`]: ../struct.Forecast.html#structfield.daily
+pub const FORECAST_DAYS: u32 = 7;
+
+/// The number of minutes covered by the
+/// [minutely block][`Forecast::minutely`].
+///
+/// [`Forecast::minutely`]: ../struct.Forecast.html#structfield.minutely
+pub const MINUTELY_MINUTES: u32 = 60;
+
+/// The number of hours covered by the [hourly block][`Forecast::hourly`].
+///
+/// [`Forecast::hourly`]: ../struct.Forecast.html#structfield.hourly
+pub const HOURLY_HOURS: u32 = 48;
+
+/// The number of hours covered by the [hourly block][`Forecast::hourly`] when
+/// [extended][`Options::extend_hourly`].
+///
+/// [`Forecast::hourly`]: ../struct.Forecast.html#structfield.hourly
+/// [`Options::extend_hourly`]: ../struct.Options.html#method.extend_hourly
+pub const EXTENDED_HOURLY_HOURS: u32 = 168;
+
+/// How many years into the past Time Machine requests are supported for.
+///
+/// Historical data is available for most locations within this range, but
+/// coverage varies.
+pub const TIME_MACHINE_YEARS_PAST: u32 = 60;
+
+/// How many years into the future Time Machine requests are supported for.
+///
+/// Requests this far ahead return statistical forecasts based on historical
+/// conditions.
+pub const TIME_MACHINE_YEARS_FUTURE: u32 = 10;
@@ -0,0 +1,207 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Standalone meteorological formulas, along with conversions between the
+//! measurement systems used by each [`Unit`].
+//!
+//! [`Unit`]: ../enum.Unit.html
+
+use Unit;
+
+/// Calculates the wind chill in degrees Fahrenheit, using the [NWS formula].
+///
+/// The temperature is in degrees Fahrenheit and the wind speed is in miles
+/// per hour.
+///
+/// Returns `None` outside of the conditions the formula is defined for: a
+/// temperature above 50°F or a wind speed below 3mph.
+///
+/// [NWS formula]: https://www.weather.gov/media/epz/wxcalc/windChill.pdf
+pub fn wind_chill(temperature: f64, wind_speed: f64) -> Option<f64> {
+    if temperature > 50.0 || wind_speed < 3.0 {
+        return None;
+    }
+
+    let wind = wind_speed.powf(0.16);
+
+    Some(35.74 + 0.6215 * temperature - 35.75 * wind + 0.4275 * temperature * wind)
+}
+
+/// Calculates the heat index in degrees Fahrenheit, using the [Rothfusz
+/// regression].
+///
+/// The temperature is in degrees Fahrenheit and the relative humidity is
+/// between `0` and `1`, as in [`Datapoint::humidity`].
+///
+/// Returns `None` for temperatures below 80°F, where the regression is not
+/// valid.
+///
+/// [`Datapoint::humidity`]: struct.Datapoint.html#structfield.humidity
+/// [Rothfusz regression]: http://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml
+pub fn heat_index(temperature: f64, humidity: f64) -> Option<f64> {
+    if temperature < 80.0 {
+        return None;
+    }
+
+    let t = temperature;
+    let rh = humidity * 100.0;
+
+    Some(-42.379
+        + 2.04901523 * t
+        + 10.14333127 * rh
+        - 0.22475541 * t * rh
+        - 0.00683783 * t * t
+        - 0.05481717 * rh * rh
+        + 0.00122874 * t * t * rh
+        + 0.00085282 * t * rh * rh
+        - 0.00000199 * t * t * rh * rh)
+}
+
+/// Converts each kind of measurement from one unit type to another, as used by
+/// [`Forecast::convert_units`].
+///
+/// Pressure is not converted, as millibars and hectopascals are equal.
+/// Measurements already on the same scale in both unit types, such as
+/// temperatures from [`Unit::Ca`] to [`Unit::Si`], are returned unchanged
+/// rather than picking up rounding errors.
+///
+/// [`Unit::Ca`]: ../enum.Unit.html#variant.Ca
+/// [`Unit::Si`]: ../enum.Unit.html#variant.Si
+/// [`Forecast::convert_units`]: ../struct.Forecast.html#method.convert_units
+pub struct Conversion {
+    from: Scales,
+    to: Scales,
+}
+
+impl Conversion {
+    /// Returns `None` if either unit type is [`Unit::Auto`], as the measurement
+    /// system it resolves to is not known.
+    ///
+    /// [`Unit::Auto`]: ../enum.Unit.html#variant.Auto
+    pub fn new(from: Unit, to: Unit) -> Option<Conversion> {
+        Some(Conversion {
+            from: Scales::of(from)?,
+            to: Scales::of(to)?,
+        })
+    }
+
+    /// Converts a temperature.
+    pub fn temperature(&self, value: f64) -> f64 {
+        if self.from.freezing == self.to.freezing {
+            return scale(value, self.from.fahrenheit, self.to.fahrenheit);
+        }
+
+        let fahrenheit = value * self.from.fahrenheit + self.from.freezing;
+
+        (fahrenheit - self.to.freezing) / self.to.fahrenheit
+    }
+
+    /// Converts a difference between temperatures, such as an error margin.
+    pub fn temperature_difference(&self, value: f64) -> f64 {
+        scale(value, self.from.fahrenheit, self.to.fahrenheit)
+    }
+
+    /// Converts a speed.
+    pub fn speed(&self, value: f64) -> f64 {
+        scale(value, self.from.mph, self.to.mph)
+    }
+
+    /// Converts a distance.
+    pub fn distance(&self, value: f64) -> f64 {
+        scale(value, self.from.miles, self.to.miles)
+    }
+
+    /// Converts a precipitation intensity.
+    pub fn precip_intensity(&self, value: f64) -> f64 {
+        scale(value, self.from.inches_per_hour, self.to.inches_per_hour)
+    }
+
+    /// Converts a precipitation accumulation.
+    pub fn precip_accumulation(&self, value: f64) -> f64 {
+        scale(value, self.from.inches, self.to.inches)
+    }
+}
+
+/// Converts a value between two scales, each the size of one of its units in
+/// the equivalent US unit, leaving it unchanged if they are equal.
+fn scale(value: f64, from: f64, to: f64) -> f64 {
+    if from == to {
+        value
+    } else {
+        value * from / to
+    }
+}
+
+/// The size of one of each of a unit type's measurements in the equivalent US
+/// measurement, such as `0.621...` miles per kilometer for [`Unit::Si`].
+///
+/// [`Unit::Si`]: ../enum.Unit.html#variant.Si
+struct Scales {
+    /// Degrees Fahrenheit per degree.
+    fahrenheit: f64,
+    /// The temperature in degrees Fahrenheit at zero degrees.
+    freezing: f64,
+    /// Miles per hour per unit of speed.
+    mph: f64,
+    /// Miles per unit of distance.
+    miles: f64,
+    /// Inches per hour per unit of precipitation intensity.
+    inches_per_hour: f64,
+    /// Inches per unit of precipitation accumulation.
+    inches: f64,
+}
+
+impl Scales {
+    fn of(unit: Unit) -> Option<Scales> {
+        let km = 1.0 / 1.609344;
+
+        Some(match unit {
+            Unit::Auto => return None,
+            Unit::Ca => Scales {
+                fahrenheit: 1.8,
+                freezing: 32.0,
+                mph: km,
+                miles: km,
+                inches_per_hour: 1.0 / 25.4,
+                inches: 1.0 / 2.54,
+            },
+            Unit::Si => Scales {
+                fahrenheit: 1.8,
+                freezing: 32.0,
+                mph: 3.6 * km,
+                miles: km,
+                inches_per_hour: 1.0 / 25.4,
+                inches: 1.0 / 2.54,
+            },
+            Unit::Uk2 => Scales {
+                fahrenheit: 1.8,
+                freezing: 32.0,
+                mph: 1.0,
+                miles: 1.0,
+                inches_per_hour: 1.0 / 25.4,
+                inches: 1.0 / 2.54,
+            },
+            Unit::Us => Scales {
+                fahrenheit: 1.0,
+                freezing: 0.0,
+                mph: 1.0,
+                miles: 1.0,
+                inches_per_hour: 1.0,
+                inches: 1.0,
+            },
+        })
+    }
+}
@@ -14,6 +14,27 @@
 // CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
+use de;
+use geo;
+use meteorology;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use {Block, Error, Result, Unit};
+
+#[cfg(feature="locale")]
+use Language;
+#[cfg(feature="chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature="timezones")]
+use chrono::Offset;
+#[cfg(feature="timezones")]
+use chrono_tz::Tz;
+#[cfg(feature="chrono")]
+use std::convert::TryFrom;
+
 /// A safe representation of the indicated weather. This is useful for matching
 /// and presenting an emoji or other weather symbol or representation.
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
@@ -59,6 +80,66 @@ pub enum Icon {
     Wind,
 }
 
+impl Icon {
+    /// A short, normalized English description of the icon, such as
+    /// `"Partly cloudy"`.
+    ///
+    /// Unlike a [`Datapoint::summary`], which is free text and varies by the
+    /// requested [`Language`], this is deterministic and suitable for screen
+    /// readers and notifications.
+    ///
+    /// [`Datapoint::summary`]: struct.Datapoint.html#structfield.summary
+    /// [`Language`]: enum.Language.html
+    pub fn description(&self) -> &'static str {
+        use self::Icon::*;
+
+        match *self {
+            ClearDay => "Clear",
+            ClearNight => "Clear",
+            Cloudy => "Cloudy",
+            Fog => "Foggy",
+            Hail => "Hail",
+            PartlyCloudyDay => "Partly cloudy",
+            PartlyCloudyNight => "Partly cloudy",
+            Rain => "Rain",
+            Sleet => "Sleet",
+            Snow => "Snow",
+            Thunderstorm => "Thunderstorms",
+            Tornado => "Tornado",
+            Wind => "Windy",
+        }
+    }
+
+    /// A representative Unicode weather emoji for the icon, such as `"☀️"` for
+    /// [`ClearDay`].
+    ///
+    /// Day and night icons are distinguished where Unicode allows: a clear
+    /// night is a crescent moon, while a partly cloudy night, lacking a
+    /// dedicated emoji, is a cloud. Hail is an ice cube, to tell it apart from
+    /// sleet's cloud with snow.
+    ///
+    /// [`ClearDay`]: #variant.ClearDay
+    pub fn emoji(&self) -> &'static str {
+        use self::Icon::*;
+
+        match *self {
+            ClearDay => "\u{2600}\u{fe0f}",
+            ClearNight => "\u{1f319}",
+            Cloudy => "\u{2601}\u{fe0f}",
+            Fog => "\u{1f32b}\u{fe0f}",
+            Hail => "\u{1f9ca}",
+            PartlyCloudyDay => "\u{26c5}",
+            PartlyCloudyNight => "\u{2601}\u{fe0f}",
+            Rain => "\u{1f327}\u{fe0f}",
+            Sleet => "\u{1f328}\u{fe0f}",
+            Snow => "\u{2744}\u{fe0f}",
+            Thunderstorm => "\u{26c8}\u{fe0f}",
+            Tornado => "\u{1f32a}\u{fe0f}",
+            Wind => "\u{1f4a8}",
+        }
+    }
+}
+
 /// The type of precipitation that is happening within a [`Datapoint`].
 ///
 /// [`Datapoint`]: struct.Datapoint.html
@@ -72,15 +153,201 @@ pub enum PrecipitationType {
     Snow,
 }
 
+impl PrecipitationType {
+    /// A capitalized English name of the precipitation type, such as
+    /// `"Rain"`.
+    pub fn description(&self) -> &'static str {
+        use self::PrecipitationType::*;
+
+        match *self {
+            Rain => "Rain",
+            Sleet => "Sleet",
+            Snow => "Snow",
+        }
+    }
+}
+
+/// One of the 16 points of the compass, such as [North][`CompassDirection::N`]
+/// or [north-northeast][`CompassDirection::NNE`].
+///
+/// [`CompassDirection::N`]: #variant.N
+/// [`CompassDirection::NNE`]: #variant.NNE
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum CompassDirection {
+    /// North.
+    N,
+    /// North-northeast.
+    NNE,
+    /// Northeast.
+    NE,
+    /// East-northeast.
+    ENE,
+    /// East.
+    E,
+    /// East-southeast.
+    ESE,
+    /// Southeast.
+    SE,
+    /// South-southeast.
+    SSE,
+    /// South.
+    S,
+    /// South-southwest.
+    SSW,
+    /// Southwest.
+    SW,
+    /// West-southwest.
+    WSW,
+    /// West.
+    W,
+    /// West-northwest.
+    WNW,
+    /// Northwest.
+    NW,
+    /// North-northwest.
+    NNW,
+}
+
+impl CompassDirection {
+    /// Converts a bearing in degrees clockwise from north into the nearest
+    /// compass point. Bearings outside of `[0, 360)` are wrapped.
+    pub fn from_bearing(degrees: f64) -> CompassDirection {
+        use self::CompassDirection::*;
+
+        const POINTS: [CompassDirection; 16] = [
+            N, NNE, NE, ENE, E, ESE, SE, SSE, S, SSW, SW, WSW, W, WNW, NW, NNW,
+        ];
+
+        let degrees = ((degrees % 360.0) + 360.0) % 360.0;
+        let index = ((degrees + 11.25) / 22.5) as usize % 16;
+
+        POINTS[index]
+    }
+
+    /// The abbreviated name of the compass point, such as `"NNE"`.
+    pub fn abbreviation(&self) -> &'static str {
+        use self::CompassDirection::*;
+
+        match *self {
+            N => "N",
+            NNE => "NNE",
+            NE => "NE",
+            ENE => "ENE",
+            E => "E",
+            ESE => "ESE",
+            SE => "SE",
+            SSE => "SSE",
+            S => "S",
+            SSW => "SSW",
+            SW => "SW",
+            WSW => "WSW",
+            W => "W",
+            WNW => "WNW",
+            NW => "NW",
+            NNW => "NNW",
+        }
+    }
+}
+
+/// One of the eight named phases of the moon, bucketed from a daily
+/// [`Datapoint::moon_phase`] fraction.
+///
+/// [`Datapoint::moon_phase`]: struct.Datapoint.html#structfield.moon_phase
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum MoonPhase {
+    /// The moon is not visible, around a fraction of `0`.
+    NewMoon,
+    /// Less than half of the moon is lit, and the lit portion is growing.
+    WaxingCrescent,
+    /// The right half of the moon is lit, around a fraction of `0.25`.
+    FirstQuarter,
+    /// More than half of the moon is lit, and the lit portion is growing.
+    WaxingGibbous,
+    /// The whole moon is lit, around a fraction of `0.5`.
+    FullMoon,
+    /// More than half of the moon is lit, and the lit portion is shrinking.
+    WaningGibbous,
+    /// The left half of the moon is lit, around a fraction of `0.75`.
+    LastQuarter,
+    /// Less than half of the moon is lit, and the lit portion is shrinking.
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Converts a fraction of the lunation, where `0` is a new moon and `0.5`
+    /// is a full moon, into the nearest named phase.
+    ///
+    /// Each phase covers an eighth of the lunation centered on its fraction,
+    /// so fractions near `1` wrap back around to a [`NewMoon`], as do
+    /// fractions outside of `[0, 1)`.
+    ///
+    /// [`NewMoon`]: #variant.NewMoon
+    pub fn from_fraction(fraction: f64) -> MoonPhase {
+        use self::MoonPhase::*;
+
+        const PHASES: [MoonPhase; 8] = [
+            NewMoon,
+            WaxingCrescent,
+            FirstQuarter,
+            WaxingGibbous,
+            FullMoon,
+            WaningGibbous,
+            LastQuarter,
+            WaningCrescent,
+        ];
+
+        let fraction = ((fraction % 1.0) + 1.0) % 1.0;
+        let index = (fraction * 8.0 + 0.5) as usize % 8;
+
+        PHASES[index]
+    }
+
+    /// A capitalized English name of the phase, such as `"Waxing crescent"`.
+    pub fn description(&self) -> &'static str {
+        use self::MoonPhase::*;
+
+        match *self {
+            NewMoon => "New moon",
+            WaxingCrescent => "Waxing crescent",
+            FirstQuarter => "First quarter",
+            WaxingGibbous => "Waxing gibbous",
+            FullMoon => "Full moon",
+            WaningGibbous => "Waning gibbous",
+            LastQuarter => "Last quarter",
+            WaningCrescent => "Waning crescent",
+        }
+    }
+
+    /// The Unicode emoji for the phase, such as `"🌓"` for a
+    /// [`FirstQuarter`], as seen from the northern hemisphere.
+    ///
+    /// [`FirstQuarter`]: #variant.FirstQuarter
+    pub fn emoji(&self) -> &'static str {
+        use self::MoonPhase::*;
+
+        match *self {
+            NewMoon => "\u{1f311}",
+            WaxingCrescent => "\u{1f312}",
+            FirstQuarter => "\u{1f313}",
+            WaxingGibbous => "\u{1f314}",
+            FullMoon => "\u{1f315}",
+            WaningGibbous => "\u{1f316}",
+            LastQuarter => "\u{1f317}",
+            WaningCrescent => "\u{1f318}",
+        }
+    }
+}
+
 /// A textual, expiring severe weather warning issued for a location. There may
 /// be multiple alerts per [`Forecast`].
 ///
 /// [`Forecast`]: struct.Forecast.html
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Alert {
     /// [Unix timestamp][unixtime] of when the alert expires.
     ///
     /// [unixtime]: https://en.wikipedia.org/wiki/Unix_time
+    #[serde(skip_serializing_if="Option::is_none")]
     pub expires: Option<u64>,
     /// A detailed description of the alert.
     pub description: String,
@@ -88,19 +355,87 @@ pub struct Alert {
     pub title: String,
     /// A URI that contains detailed information about the alert.
     pub uri: String,
+    /// The names of the regions covered by the alert.
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub regions: Option<Vec<String>>,
+    /// The severity of the alert.
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub severity: Option<AlertSeverity>,
+    /// [Unix timestamp][unixtime] of when the alert was issued.
+    ///
+    /// [unixtime]: https://en.wikipedia.org/wiki/Unix_time
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub time: Option<u64>,
+}
+
+impl Alert {
+    /// The [`expires`] timestamp as a `SystemTime`.
+    ///
+    /// Returns `None` if there is no expiry, or if the timestamp can not be
+    /// represented on this platform.
+    ///
+    /// [`expires`]: #structfield.expires
+    pub fn expires_system_time(&self) -> Option<SystemTime> {
+        self.expires.and_then(unix_to_system_time)
+    }
+
+    /// The [`expires`] timestamp as a UTC `DateTime`.
+    ///
+    /// Returns `None` if there is no expiry, or if the timestamp is out of
+    /// chrono's range.
+    ///
+    /// This requires the `chrono` feature.
+    ///
+    /// [`expires`]: #structfield.expires
+    #[cfg(feature="chrono")]
+    pub fn expires_utc(&self) -> Option<DateTime<Utc>> {
+        self.expires.and_then(unix_to_utc)
+    }
+}
+
+/// The severity of an [`Alert`], ordered from least to most severe.
+///
+/// This is useful for deciding whether an alert warrants a notification.
+///
+/// [`Alert`]: struct.Alert.html
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub enum AlertSeverity {
+    /// An individual should be aware of potentially severe weather.
+    #[serde(rename="advisory")]
+    Advisory,
+    /// An individual should prepare for potentially severe weather.
+    #[serde(rename="watch")]
+    Watch,
+    /// An individual should take immediate action to protect themselves and
+    /// others from potentially severe weather.
+    #[serde(rename="warning")]
+    Warning,
 }
 
 /// A block of data within a [`Forecast`], with potentially many [`Datapoint`]s.
 ///
 /// [`Datapoint`]: struct.Datapoint.html
 /// [`Forecast`]: struct.Forecast.html
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Datablock {
+    #[serde(skip_serializing_if="Option::is_none")]
     pub data: Option<Vec<Datapoint>>,
+    #[serde(skip_serializing_if="Option::is_none")]
     pub icon: Option<Icon>,
+    #[serde(skip_serializing_if="Option::is_none")]
     pub summary: Option<String>,
 }
 
+impl Datablock {
+    /// The datapoints of the block, or an empty slice if its [`data`] is
+    /// absent.
+    ///
+    /// [`data`]: #structfield.data
+    pub fn points(&self) -> &[Datapoint] {
+        self.data.as_ref().map(|data| &data[..]).unwrap_or(&[])
+    }
+}
+
 /// A datapoint within a [`Datablock`], where there is usually multiple.
 ///
 /// All fields are optional _except for [`time`]_, as some data may not be
@@ -116,95 +451,845 @@ pub struct Datablock {
 ///
 /// [`Datablock`]: struct.Datablock.html
 /// [`time`]: #structfield.time
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all="camelCase")]
 pub struct Datapoint {
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub apparent_temperature_max_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub apparent_temperature_max: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub apparent_temperature_min_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub apparent_temperature_min: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub apparent_temperature: Option<f64>,
+    /// Whether the [`apparent_temperature`] was estimated locally by
+    /// [`Forecast::fill_apparent_temperature`] rather than returned by the
+    /// API.
+    ///
+    /// This is not serialized.
+    ///
+    /// [`Forecast::fill_apparent_temperature`]: struct.Forecast.html#method.fill_apparent_temperature
+    /// [`apparent_temperature`]: #structfield.apparent_temperature
+    #[serde(skip)]
+    pub apparent_temperature_estimated: bool,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub cloud_cover_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub cloud_cover: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub dew_point_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub dew_point: Option<f64>,
+    /// Fields sent by the API which are not otherwise known to the crate,
+    /// such as fields newly added to the API, keyed by their name as sent.
+    ///
+    /// These are serialized along with the known fields. The `*Error`
+    /// confidence fields skipped by the `compact` feature are not captured.
+    ///
+    /// This is only filled with the `extra-fields` feature, and is otherwise
+    /// always empty.
+    #[cfg_attr(feature="extra-fields", serde(flatten, deserialize_with="::de::extra_fields"))]
+    #[cfg_attr(not(feature="extra-fields"), serde(skip))]
+    pub extra: BTreeMap<String, Value>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub humidity_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub humidity: Option<f64>,
+    #[serde(skip_serializing_if="Option::is_none")]
     pub icon: Option<Icon>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub moon_phase: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub nearest_storm_bearing: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub nearest_storm_distance: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub ozone_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub ozone: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_accumulation_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_accumulation: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_intensity_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_intensity_max_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_intensity_max_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_intensity_max: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_intensity: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_probability_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_probability: Option<f64>,
+    #[serde(skip_serializing_if="Option::is_none")]
     pub precip_type: Option<PrecipitationType>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub pressure_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub pressure: Option<f64>,
+    #[serde(skip_serializing_if="Option::is_none")]
     pub summary: Option<String>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub sunrise_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub sunset_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_max_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_max_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_max: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_min_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_min_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_min: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub temperature: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(deserialize_with="::de::u64"))]
     pub time: u64,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub uv_index_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub uv_index: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub visibility_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub visibility: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub wind_bearing_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub wind_bearing: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_u64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub wind_gust_time: Option<u64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub wind_gust: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[cfg_attr(feature="compact", serde(skip))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub wind_speed_error: Option<f64>,
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
     pub wind_speed: Option<f64>,
 }
 
+impl Datapoint {
+    /// The [dew point spread][`dew_point_spread`] below which
+    /// [fog is considered likely][`fog_likely`], in degrees.
+    ///
+    /// [`dew_point_spread`]: #method.dew_point_spread
+    /// [`fog_likely`]: #method.fog_likely
+    pub const FOG_SPREAD_THRESHOLD: f64 = 2.5;
+
+    /// The [`precip_probability`] above which a datapoint with a
+    /// [`precip_type`] [is considered precipitating][`is_precipitating`].
+    ///
+    /// [`is_precipitating`]: #method.is_precipitating
+    /// [`precip_probability`]: #structfield.precip_probability
+    /// [`precip_type`]: #structfield.precip_type
+    pub const PRECIPITATION_THRESHOLD: f64 = 0.0;
+
+    /// The number of hectopascals in an inch of mercury, for
+    /// [`pressure_inhg`].
+    ///
+    /// [`pressure_inhg`]: #method.pressure_inhg
+    pub const HPA_PER_INHG: f64 = 33.863_886_666_7;
+
+    /// The [`time`] of the datapoint as a `SystemTime`.
+    ///
+    /// Returns `None` only if the timestamp can not be represented on this
+    /// platform.
+    ///
+    /// [`time`]: #structfield.time
+    pub fn system_time(&self) -> Option<SystemTime> {
+        unix_to_system_time(self.time)
+    }
+
+    /// The [`sunrise_time`] as a `SystemTime`, if present.
+    ///
+    /// [`sunrise_time`]: #structfield.sunrise_time
+    pub fn sunrise_system_time(&self) -> Option<SystemTime> {
+        self.sunrise_time.and_then(unix_to_system_time)
+    }
+
+    /// The [`sunset_time`] as a `SystemTime`, if present.
+    ///
+    /// [`sunset_time`]: #structfield.sunset_time
+    pub fn sunset_system_time(&self) -> Option<SystemTime> {
+        self.sunset_time.and_then(unix_to_system_time)
+    }
+
+    /// The [`time`] of the datapoint as a UTC `DateTime`.
+    ///
+    /// Returns `None` only if the timestamp is out of chrono's range. Other
+    /// timestamps, such as [`temperature_max_time`], can be converted with
+    /// [`unix_to_utc`].
+    ///
+    /// This requires the `chrono` feature.
+    ///
+    /// [`temperature_max_time`]: #structfield.temperature_max_time
+    /// [`time`]: #structfield.time
+    /// [`unix_to_utc`]: fn.unix_to_utc.html
+    #[cfg(feature="chrono")]
+    pub fn time_utc(&self) -> Option<DateTime<Utc>> {
+        unix_to_utc(self.time)
+    }
+
+    /// The [`sunrise_time`] as a UTC `DateTime`, if present.
+    ///
+    /// This requires the `chrono` feature.
+    ///
+    /// [`sunrise_time`]: #structfield.sunrise_time
+    #[cfg(feature="chrono")]
+    pub fn sunrise_utc(&self) -> Option<DateTime<Utc>> {
+        self.sunrise_time.and_then(unix_to_utc)
+    }
+
+    /// The [`sunset_time`] as a UTC `DateTime`, if present.
+    ///
+    /// This requires the `chrono` feature.
+    ///
+    /// [`sunset_time`]: #structfield.sunset_time
+    #[cfg(feature="chrono")]
+    pub fn sunset_utc(&self) -> Option<DateTime<Utc>> {
+        self.sunset_time.and_then(unix_to_utc)
+    }
+
+    /// Whether the datapoint appears to be from a [daily block][`daily`],
+    /// based on the presence of fields which are only sent for days: the
+    /// [`temperature_max`], [`temperature_min`], [`sunrise_time`], and
+    /// [`sunset_time`].
+    ///
+    /// This is a heuristic for presenting a datapoint without knowing which
+    /// block it came from. It may be wrong for datapoints with most of their
+    /// fields absent, such as from Time Machine requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Datapoint;
+    ///
+    /// let datapoint = Datapoint {
+    ///     temperature_max: Some(68.2),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// assert!(datapoint.is_daily());
+    /// assert!(!Datapoint::default().is_daily());
+    /// ```
+    ///
+    /// [`daily`]: struct.Forecast.html#structfield.daily
+    /// [`sunrise_time`]: #structfield.sunrise_time
+    /// [`sunset_time`]: #structfield.sunset_time
+    /// [`temperature_max`]: #structfield.temperature_max
+    /// [`temperature_min`]: #structfield.temperature_min
+    pub fn is_daily(&self) -> bool {
+        self.temperature_max.is_some()
+            || self.temperature_min.is_some()
+            || self.sunrise_time.is_some()
+            || self.sunset_time.is_some()
+    }
+
+    /// Whether the datapoint appears to be the
+    /// [current conditions][`currently`], based on the presence of the
+    /// [`nearest_storm_distance`] or [`nearest_storm_bearing`], which are only
+    /// sent for the current conditions, and the datapoint not appearing to be
+    /// [daily][`is_daily`].
+    ///
+    /// Like [`is_daily`], this is a heuristic.
+    ///
+    /// [`currently`]: struct.Forecast.html#structfield.currently
+    /// [`is_daily`]: #method.is_daily
+    /// [`nearest_storm_bearing`]: #structfield.nearest_storm_bearing
+    /// [`nearest_storm_distance`]: #structfield.nearest_storm_distance
+    pub fn is_currently(&self) -> bool {
+        !self.is_daily()
+            && (self.nearest_storm_distance.is_some() || self.nearest_storm_bearing.is_some())
+    }
+
+    /// The temperature to show the user: the [`apparent_temperature`] if
+    /// present, falling back to the actual [`temperature`].
+    ///
+    /// Use [`display_temperature_with_source`] to know which was used.
+    ///
+    /// [`apparent_temperature`]: #structfield.apparent_temperature
+    /// [`display_temperature_with_source`]: #method.display_temperature_with_source
+    /// [`temperature`]: #structfield.temperature
+    pub fn display_temperature(&self) -> Option<f64> {
+        self.display_temperature_with_source().map(|(temperature, _)| temperature)
+    }
+
+    /// Like [`display_temperature`], additionally returning `true` if the
+    /// [`apparent_temperature`] was used, or `false` if the actual
+    /// [`temperature`] was.
+    ///
+    /// [`apparent_temperature`]: #structfield.apparent_temperature
+    /// [`display_temperature`]: #method.display_temperature
+    /// [`temperature`]: #structfield.temperature
+    pub fn display_temperature_with_source(&self) -> Option<(f64, bool)> {
+        match (self.apparent_temperature, self.temperature) {
+            (Some(apparent), _) => Some((apparent, true)),
+            (None, Some(actual)) => Some((actual, false)),
+            (None, None) => None,
+        }
+    }
+
+    /// The [`temperature`] in degrees Celsius, given the unit type the
+    /// datapoint was fetched in.
+    ///
+    /// Returns `None` if the temperature is absent, or if `from` is
+    /// [`Unit::Auto`], as the unit type it resolved to is not known. The
+    /// resolved unit type is given by the [`Flags::units`] of the forecast.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Datapoint, Unit};
+    ///
+    /// let datapoint = Datapoint {
+    ///     temperature: Some(212.0),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// assert_eq!(datapoint.temperature_celsius(Unit::Us), Some(100.0));
+    /// assert_eq!(datapoint.temperature_celsius(Unit::Auto), None);
+    /// ```
+    ///
+    /// [`Flags::units`]: struct.Flags.html#structfield.units
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`temperature`]: #structfield.temperature
+    pub fn temperature_celsius(&self, from: Unit) -> Option<f64> {
+        let conversion = meteorology::Conversion::new(from, Unit::Si)?;
+
+        self.temperature.map(|temperature| conversion.temperature(temperature))
+    }
+
+    /// The [`temperature`] in degrees Fahrenheit, given the unit type the
+    /// datapoint was fetched in.
+    ///
+    /// Returns `None` if the temperature is absent, or if `from` is
+    /// [`Unit::Auto`], as for [`temperature_celsius`].
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`temperature`]: #structfield.temperature
+    /// [`temperature_celsius`]: #method.temperature_celsius
+    pub fn temperature_fahrenheit(&self, from: Unit) -> Option<f64> {
+        let conversion = meteorology::Conversion::new(from, Unit::Us)?;
+
+        self.temperature.map(|temperature| conversion.temperature(temperature))
+    }
+
+    /// The difference between the [`temperature`] and the [`dew_point`], in
+    /// the unit type the datapoint was fetched in. The smaller the spread, the
+    /// more likely fog and condensation are.
+    ///
+    /// Returns `None` if either is absent.
+    ///
+    /// [`dew_point`]: #structfield.dew_point
+    /// [`temperature`]: #structfield.temperature
+    pub fn dew_point_spread(&self) -> Option<f64> {
+        Some(self.temperature? - self.dew_point?)
+    }
+
+    /// Whether fog is likely, based on the [`dew_point_spread`] being below
+    /// [`FOG_SPREAD_THRESHOLD`] degrees.
+    ///
+    /// The threshold is the same regardless of the unit type, making this a
+    /// slightly stricter heuristic for Fahrenheit than for Celsius.
+    ///
+    /// Returns `None` if the temperature or dew point is absent.
+    ///
+    /// [`FOG_SPREAD_THRESHOLD`]: #associatedconstant.FOG_SPREAD_THRESHOLD
+    /// [`dew_point_spread`]: #method.dew_point_spread
+    pub fn fog_likely(&self) -> Option<bool> {
+        self.dew_point_spread().map(|spread| spread < Datapoint::FOG_SPREAD_THRESHOLD)
+    }
+
+    /// The sea-level [`pressure`] in hectopascals.
+    ///
+    /// The API always sends the pressure in hectopascals, regardless of the
+    /// requested [`Unit`]. [US units][`Unit::Us`] label it as millibars, which
+    /// are the same size, so this is the [`pressure`] unchanged, and exists to
+    /// make the unit explicit at the call site.
+    ///
+    /// [`Unit`]: enum.Unit.html
+    /// [`Unit::Us`]: enum.Unit.html#variant.Us
+    /// [`pressure`]: #structfield.pressure
+    pub fn pressure_hpa(&self) -> Option<f64> {
+        self.pressure
+    }
+
+    /// The sea-level [`pressure`] in inches of mercury, as commonly reported
+    /// in the United States.
+    ///
+    /// The API always sends the pressure in hectopascals, even with
+    /// [US units][`Unit::Us`], so this conversion applies regardless of the
+    /// requested [`Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Datapoint;
+    ///
+    /// let datapoint = Datapoint {
+    ///     pressure: Some(1013.25),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// let inhg = datapoint.pressure_inhg().unwrap();
+    /// assert!((inhg - 29.92).abs() < 0.01);
+    /// ```
+    ///
+    /// [`Unit`]: enum.Unit.html
+    /// [`Unit::Us`]: enum.Unit.html#variant.Us
+    /// [`pressure`]: #structfield.pressure
+    pub fn pressure_inhg(&self) -> Option<f64> {
+        self.pressure.map(|pressure| pressure / Datapoint::HPA_PER_INHG)
+    }
+
+    /// The [`visibility`] in kilometers, given the [`Unit`] the datapoint was
+    /// requested in.
+    ///
+    /// Returns `None` if the visibility is absent, or if the unit is
+    /// [`Unit::Auto`], as the measurement system it resolved to is not known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Datapoint, Unit};
+    ///
+    /// let datapoint = Datapoint {
+    ///     visibility: Some(10.0),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// let km = datapoint.visibility_km(Unit::Us).unwrap();
+    /// assert!((km - 16.09).abs() < 0.01);
+    /// assert_eq!(datapoint.visibility_km(Unit::Si), Some(10.0));
+    /// assert_eq!(datapoint.visibility_km(Unit::Auto), None);
+    /// ```
+    ///
+    /// [`Unit`]: enum.Unit.html
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`visibility`]: #structfield.visibility
+    pub fn visibility_km(&self, from: Unit) -> Option<f64> {
+        let conversion = meteorology::Conversion::new(from, Unit::Si)?;
+
+        self.visibility.map(|visibility| conversion.distance(visibility))
+    }
+
+    /// The [`visibility`] in miles, given the [`Unit`] the datapoint was
+    /// requested in.
+    ///
+    /// Returns `None` if the visibility is absent, or if the unit is
+    /// [`Unit::Auto`], as the measurement system it resolved to is not known.
+    ///
+    /// [`Unit`]: enum.Unit.html
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`visibility`]: #structfield.visibility
+    pub fn visibility_miles(&self, from: Unit) -> Option<f64> {
+        let conversion = meteorology::Conversion::new(from, Unit::Us)?;
+
+        self.visibility.map(|visibility| conversion.distance(visibility))
+    }
+
+    /// Whether precipitation is expected, based on the [`precip_probability`]
+    /// exceeding [`PRECIPITATION_THRESHOLD`] and a [`precip_type`] being
+    /// present.
+    ///
+    /// The API only sends a [`precip_type`] when the [`precip_probability`] is
+    /// above zero, so an absent type means no precipitation rather than an
+    /// unknown one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Datapoint, PrecipitationType};
+    ///
+    /// let rainy = Datapoint {
+    ///     precip_probability: Some(0.6),
+    ///     precip_type: Some(PrecipitationType::Rain),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// assert!(rainy.is_precipitating());
+    /// assert!(!Datapoint::default().is_precipitating());
+    /// ```
+    ///
+    /// [`PRECIPITATION_THRESHOLD`]: #associatedconstant.PRECIPITATION_THRESHOLD
+    /// [`precip_probability`]: #structfield.precip_probability
+    /// [`precip_type`]: #structfield.precip_type
+    pub fn is_precipitating(&self) -> bool {
+        let probability = self.precip_probability.unwrap_or(0.0);
+
+        probability > Datapoint::PRECIPITATION_THRESHOLD && self.precip_type.is_some()
+    }
+
+    /// The [`precip_type`] if the datapoint
+    /// [is precipitating][`is_precipitating`], or `None` for no precipitation.
+    ///
+    /// Unlike the [`precip_type`] itself, this is `None` for a type sent along
+    /// with a zero [`precip_probability`].
+    ///
+    /// [`is_precipitating`]: #method.is_precipitating
+    /// [`precip_probability`]: #structfield.precip_probability
+    /// [`precip_type`]: #structfield.precip_type
+    pub fn precip_type_or_none(&self) -> Option<PrecipitationType> {
+        if self.is_precipitating() {
+            self.precip_type
+        } else {
+            None
+        }
+    }
+
+    /// The abbreviated [compass point][`CompassDirection`] that the wind is
+    /// blowing from, such as `"NNE"`, from the [`wind_bearing`].
+    ///
+    /// Bearings within 11.25 degrees of north on either side, including those
+    /// from `348.75` up to `360`, are `"N"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Datapoint;
+    ///
+    /// let datapoint = Datapoint {
+    ///     wind_bearing: Some(350.0),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// assert_eq!(datapoint.wind_cardinal(), Some("N"));
+    /// assert_eq!(Datapoint::default().wind_cardinal(), None);
+    /// ```
+    ///
+    /// [`CompassDirection`]: enum.CompassDirection.html
+    /// [`wind_bearing`]: #structfield.wind_bearing
+    pub fn wind_cardinal(&self) -> Option<&'static str> {
+        self.wind_bearing
+            .map(|bearing| CompassDirection::from_bearing(bearing).abbreviation())
+    }
+
+    /// The named [phase][`MoonPhase`] of the [`moon_phase`] fraction.
+    ///
+    /// The API only sends a moon phase on [daily][`is_daily`] datapoints, so
+    /// this is `None` for others, as well as for a non-finite fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Datapoint, MoonPhase};
+    ///
+    /// let datapoint = Datapoint {
+    ///     moon_phase: Some(0.97),
+    ///     ..Datapoint::default()
+    /// };
+    ///
+    /// assert_eq!(datapoint.moon_phase_name(), Some(MoonPhase::NewMoon));
+    /// ```
+    ///
+    /// [`MoonPhase`]: enum.MoonPhase.html
+    /// [`is_daily`]: #method.is_daily
+    /// [`moon_phase`]: #structfield.moon_phase
+    pub fn moon_phase_name(&self) -> Option<MoonPhase> {
+        self.moon_phase
+            .filter(|fraction| fraction.is_finite())
+            .map(MoonPhase::from_fraction)
+    }
+
+    /// The [emoji][`MoonPhase::emoji`] of the [named moon
+    /// phase][`moon_phase_name`], such as `"🌕"` for a full moon.
+    ///
+    /// [`MoonPhase::emoji`]: enum.MoonPhase.html#method.emoji
+    /// [`moon_phase_name`]: #method.moon_phase_name
+    pub fn moon_phase_emoji(&self) -> Option<&'static str> {
+        self.moon_phase_name().map(|phase| phase.emoji())
+    }
+
+    /// A language-independent label combining the [icon's
+    /// description][`Icon::description`] and the rounded [`temperature`],
+    /// such as `"Partly cloudy, 72°"`.
+    ///
+    /// Returns `None` if both the icon and temperature are absent.
+    ///
+    /// [`Icon::description`]: enum.Icon.html#method.description
+    /// [`temperature`]: #structfield.temperature
+    pub fn accessible_label(&self) -> Option<String> {
+        match (self.icon, self.temperature) {
+            (Some(icon), Some(temp)) => Some(format!("{}, {:.0}°", icon.description(), temp)),
+            (Some(icon), None) => Some(icon.description().to_owned()),
+            (None, Some(temp)) => Some(format!("{:.0}°", temp)),
+            (None, None) => None,
+        }
+    }
+
+    /// An ordered list of labelled, formatted values for display in a table,
+    /// such as `("Temperature", "72.4 °F")`.
+    ///
+    /// Values are formatted with the symbols of the given unit type, which
+    /// should be the one the datapoint was fetched in. No symbols are added
+    /// for [`Unit::Auto`]. Absent fields are skipped.
+    ///
+    /// The rows are always in the following order: Summary, Temperature,
+    /// Feels like, High, Low, Dew point, Humidity, Precipitation chance,
+    /// Precipitation type, Precipitation intensity, Precipitation
+    /// accumulation, Wind speed, Wind gust, Wind bearing, Cloud cover,
+    /// Pressure, Visibility, Ozone.
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    pub fn display_rows(&self, unit: Unit) -> Vec<(&'static str, String)> {
+        fn measure(value: Option<f64>, precision: usize, symbol: Option<&str>) -> Option<String> {
+            value.map(|value| match symbol {
+                Some(symbol) => format!("{:.*} {}", precision, value, symbol),
+                None => format!("{:.*}", precision, value),
+            })
+        }
+
+        fn percent(value: Option<f64>) -> Option<String> {
+            value.map(|value| format!("{:.0}%", value * 100.0))
+        }
+
+        let temperature = unit.temperature_symbol();
+
+        let rows = vec![
+            ("Summary", self.summary.clone()),
+            ("Temperature", measure(self.temperature, 1, temperature)),
+            ("Feels like", measure(self.apparent_temperature, 1, temperature)),
+            ("High", measure(self.temperature_max, 1, temperature)),
+            ("Low", measure(self.temperature_min, 1, temperature)),
+            ("Dew point", measure(self.dew_point, 1, temperature)),
+            ("Humidity", percent(self.humidity)),
+            ("Precipitation chance", percent(self.precip_probability)),
+            ("Precipitation type", self.precip_type.map(|kind| kind.description().to_owned())),
+            ("Precipitation intensity", measure(self.precip_intensity, 3, unit.precip_intensity_symbol())),
+            ("Precipitation accumulation", measure(self.precip_accumulation, 2, unit.precip_accumulation_symbol())),
+            ("Wind speed", measure(self.wind_speed, 1, unit.speed_symbol())),
+            ("Wind gust", measure(self.wind_gust, 1, unit.speed_symbol())),
+            ("Wind bearing", self.wind_bearing.map(|bearing| {
+                format!("{:.0}° ({})", bearing, CompassDirection::from_bearing(bearing).abbreviation())
+            })),
+            ("Cloud cover", percent(self.cloud_cover)),
+            ("Pressure", measure(self.pressure, 1, unit.pressure_symbol())),
+            ("Visibility", measure(self.visibility, 1, unit.distance_symbol())),
+            ("Ozone", measure(self.ozone, 1, Some("DU"))),
+        ];
+
+        rows.into_iter()
+            .filter_map(|(label, value)| value.map(|value| (label, value)))
+            .collect()
+    }
+
+    /// Formats the [`temperature`] to one decimal place with the decimal
+    /// separator of the given language and the [symbol][`Unit::temperature_symbol`]
+    /// of the unit it was fetched in, such as `"22,4 °C"` for German.
+    ///
+    /// Only the decimal separator is currently localized, for a handful of
+    /// languages; all others use a period.
+    ///
+    /// Returns `None` if the temperature is absent or the unit is
+    /// [`Unit::Auto`].
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`Unit::temperature_symbol`]: enum.Unit.html#method.temperature_symbol
+    /// [`temperature`]: #structfield.temperature
+    #[cfg(feature="locale")]
+    pub fn format_temperature(&self, unit: Unit, lang: Language) -> Option<String> {
+        use Language::*;
+
+        let temperature = self.temperature?;
+        let symbol = unit.temperature_symbol()?;
+        let value = format!("{:.1}", temperature);
+
+        let value = match lang {
+            Bg | Ca | Cs | Da | De | Es | Et | Fi | Fr | It | Lv | Nl | Pl | Pt | Ro | Ru | Sl
+                | Sv => value.replace('.', ","),
+            _ => value,
+        };
+
+        Some(format!("{} {}", value, symbol))
+    }
+}
+
+/// A span of time between two [Unix timestamps][unixtime].
+///
+/// [unixtime]: https://en.wikipedia.org/wiki/Unix_time
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TimeWindow {
+    /// The start of the window.
+    pub start: u64,
+    /// The end of the window.
+    pub end: u64,
+}
+
+/// The sunrise and sunset of a day, along with the golden and blue hour
+/// windows derived from them, as returned by [`Forecast::sun_events`].
+///
+/// The golden hours are approximated as the hour after sunrise and the hour
+/// before sunset, and the blue hours as the half hour before sunrise and the
+/// half hour after sunset. The actual durations vary by latitude and season.
+///
+/// [`Forecast::sun_events`]: struct.Forecast.html#method.sun_events
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SunEvents {
+    /// The time of sunrise.
+    pub sunrise: u64,
+    /// The time of sunset.
+    pub sunset: u64,
+    /// The blue hour before sunrise.
+    pub morning_blue_hour: TimeWindow,
+    /// The golden hour after sunrise.
+    pub morning_golden_hour: TimeWindow,
+    /// The golden hour before sunset.
+    pub evening_golden_hour: TimeWindow,
+    /// The blue hour after sunset.
+    pub evening_blue_hour: TimeWindow,
+}
+
+impl SunEvents {
+    /// The approximate length of a golden hour, in seconds.
+    pub const GOLDEN_HOUR: u64 = 60 * 60;
+
+    /// The approximate length of a blue hour, in seconds.
+    pub const BLUE_HOUR: u64 = 30 * 60;
+
+    /// Derives the sun events from a sunrise and sunset.
+    pub fn new(sunrise: u64, sunset: u64) -> SunEvents {
+        SunEvents {
+            sunrise,
+            sunset,
+            morning_blue_hour: TimeWindow {
+                start: sunrise.saturating_sub(Self::BLUE_HOUR),
+                end: sunrise,
+            },
+            morning_golden_hour: TimeWindow {
+                start: sunrise,
+                end: sunrise.saturating_add(Self::GOLDEN_HOUR),
+            },
+            evening_golden_hour: TimeWindow {
+                start: sunset.saturating_sub(Self::GOLDEN_HOUR),
+                end: sunset,
+            },
+            evening_blue_hour: TimeWindow {
+                start: sunset,
+                end: sunset.saturating_add(Self::BLUE_HOUR),
+            },
+        }
+    }
+}
+
 /// A set of flags for a forecast, such as the [`Unit`]s specified or the vector
 /// of [DarkSky] stations reporting.
 ///
 /// [`Unit`]: enum.Unit.html
 /// [DarkSky]: https://darksky.net
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all="kebab-case")]
 pub struct Flags {
     /// A list of DarkSky stations used for the [`Forecast`].
     ///
     /// [`Forecast`]: struct.Forecast.html
+    #[serde(skip_serializing_if="Option::is_none")]
     pub darksky_stations: Option<Vec<String>>,
     /// A list of the unavailable DarkSky stations.
+    #[serde(skip_serializing_if="Option::is_none")]
     pub darksky_unavailable: Option<String>,
     /// A list of the
+    #[serde(skip_serializing_if="Option::is_none")]
     pub datapoint_stations: Option<Vec<String>>,
     /// A list of [ISD] stations used.
     ///
     /// [ISD]: https://www.ncdc.noaa.gov/isd
+    #[serde(skip_serializing_if="Option::is_none")]
     pub isd_stations: Option<Vec<String>>,
     /// A list of [LAMP] stations used to obtain the information.
     ///
     /// [LAMP]: http://www.nws.noaa.gov/mdl/lamp/lamp_info.shtml
+    #[serde(skip_serializing_if="Option::is_none")]
     pub lamp_stations: Option<Vec<String>>,
     /// A list of [METAR] stations used to obtain the information.
     ///
     /// [METAR]: https://www.aviationweather.gov/metar
+    #[serde(skip_serializing_if="Option::is_none")]
     pub metar_stations: Option<Vec<String>>,
     /// The [METNO license] used.
     ///
     /// [METNO license]: http://www.met.no/
+    #[serde(skip_serializing_if="Option::is_none")]
     pub metno_license: Option<String>,
     /// A list of sources used to obtain the information.
+    #[serde(skip_serializing_if="Option::is_none")]
     pub sources: Option<Vec<String>>,
     /// The [`Unit`]s used to format the data.
     ///
     /// [`Unit`]: enum.Unit.html
+    #[serde(skip_serializing_if="Option::is_none")]
     pub units: Option<String>,
 }
 
@@ -214,12 +1299,35 @@ pub struct Flags {
 /// Most of the fields are optional, due to being able to be excluded via the
 /// [`Options`] builder.
 ///
+/// Serialization is deterministic: serializing the same forecast always
+/// produces byte-identical output, with fields in the order they are declared,
+/// so the output is suitable for hashing, such as for content-addressed
+/// caching. Any maps added to the models must be `BTreeMap`s to uphold this.
+///
+/// Fields are serialized under the names the API sends them with, such as
+/// `apparentTemperature` for datapoints and `isd-stations` for flags, so
+/// the output can be fed to other consumers of the DarkSky format.
+///
+/// Forecasts, like their datablocks and datapoints, can be compared with `==`.
+/// As with any `f64`, numeric fields are compared exactly, so values which
+/// went through arithmetic or a lossy format may differ in their last bits;
+/// use [`content_eq_within`] to compare with a tolerance instead.
+///
 /// [`Options`]: struct.Options.html
+/// [`content_eq_within`]: #method.content_eq_within
 /// [`get_forecast`]: fn.get_forecast.html
 /// [`get_forecast_with_options`]: fn.get_forecast_with_options.html
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Forecast {
-    #[serde(default)]
+    /// Severe weather alerts issued for the location.
+    ///
+    /// This is empty when no alerts were returned, including when excluded
+    /// by passing the [`Block::Alerts`] variant to [`Options::exclude`]. A
+    /// single alert object sent in place of an array is also accepted.
+    ///
+    /// [`Block::Alerts`]: enum.Block.html#variant.Alerts
+    /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(default, deserialize_with="::de::one_or_many", skip_serializing_if="Vec::is_empty")]
     pub alerts: Vec<Alert>,
     /// The current forecast.
     ///
@@ -229,6 +1337,7 @@ pub struct Forecast {
     /// [`Block::Currently`]: enum.Block.html#variant.Currently
     /// [`Datablock`]: struct.Datablock.html
     /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(skip_serializing_if="Option::is_none")]
     pub currently: Option<Datapoint>,
     /// Daily [`Datablock`]s within a forecast.
     ///
@@ -238,7 +1347,19 @@ pub struct Forecast {
     /// [`Block::Daily`]: enum.Block.html#variant.Daily
     /// [`Datablock`]: struct.Datablock.html
     /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(skip_serializing_if="Option::is_none")]
     pub daily: Option<Datablock>,
+    /// Top-level fields sent by the API which are not otherwise known to the
+    /// crate, keyed by their name as sent. Refer to [`Datapoint::extra`] for
+    /// the fields of datapoints.
+    ///
+    /// This is only filled with the `extra-fields` feature, and is otherwise
+    /// always empty.
+    ///
+    /// [`Datapoint::extra`]: struct.Datapoint.html#structfield.extra
+    #[cfg_attr(feature="extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature="extra-fields"), serde(skip))]
+    pub extra: BTreeMap<String, Value>,
     /// A set of flags returned from the API.
     ///
     /// This may be excluded by passing the [`Block::Flags`] variant to
@@ -247,6 +1368,7 @@ pub struct Forecast {
     /// [`Block::Flags`]: enum.Block.html#variant.Flags
     /// [`Datablock`]: struct.Datablock.html
     /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(skip_serializing_if="Option::is_none")]
     pub flags: Option<Flags>,
     /// Hourly [`Datablock`]s within a forecast.
     ///
@@ -256,10 +1378,13 @@ pub struct Forecast {
     /// [`Block::Hourly`]: enum.Block.html#variant.Hourly
     /// [`Datablock`]: struct.Datablock.html
     /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(skip_serializing_if="Option::is_none")]
     pub hourly: Option<Datablock>,
     /// The latitude of the forecast's location.
+    #[cfg_attr(feature="lenient-numbers", serde(deserialize_with="::de::f64"))]
     pub latitude: f64,
     /// The longitude of the forecast's location.
+    #[cfg_attr(feature="lenient-numbers", serde(deserialize_with="::de::f64"))]
     pub longitude: f64,
     /// Minutely [`Datablock`]s within a forecast.
     ///
@@ -269,9 +1394,994 @@ pub struct Forecast {
     /// [`Block::Minutely`]: enum.Block.html#variant.Minutely
     /// [`Datablock`]: struct.Datablock.html
     /// [`Options::exclude`]: struct.Options.html#method.exclude
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub minutely: Option<Datablock>,
+    /// The timezone offset of the forecast in hours, relative to the UTC
+    /// timezone.
+    ///
+    /// This is deprecated by the API in favor of the IANA [`timezone`], as a
+    /// single offset does not account for daylight saving transitions within
+    /// the forecast. Use [`utc_offset_seconds`] for the offset in seconds.
+    ///
+    /// [`timezone`]: #structfield.timezone
+    /// [`utc_offset_seconds`]: #method.utc_offset_seconds
+    #[cfg_attr(feature="lenient-numbers", serde(default, deserialize_with="::de::option_f64"))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub offset: Option<f64>,
+    /// The IANA name of the timezone, such as `America/Los_Angeles`.
+    pub timezone: String,
+}
+
+/// The default tolerance used by [`Forecast::content_eq`] when comparing
+/// floating point values.
+///
+/// [`Forecast::content_eq`]: struct.Forecast.html#method.content_eq
+pub const DEFAULT_CONTENT_TOLERANCE: f64 = 0.01;
+
+impl Forecast {
+    /// Parses a forecast from a JSON response body, such as one previously
+    /// cached to disk or a database, without an HTTP client.
+    ///
+    /// Decoding behaves the same as for forecasts from a requester, including
+    /// the `lenient-numbers` and `serde_path_to_error` features.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// let json = r#"{"latitude": 37.8267, "longitude": -122.423, "timezone": "America/Los_Angeles"}"#;
+    /// let forecast = Forecast::from_json_str(json).unwrap();
+    ///
+    /// assert_eq!(forecast.timezone, "America/Los_Angeles");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the JSON is invalid or is not a forecast, or
+    /// [`Error::DecodePath`] with the `serde_path_to_error` feature.
+    ///
+    /// [`Error::DecodePath`]: enum.Error.html#variant.DecodePath
+    /// [`Error::Json`]: enum.Error.html#variant.Json
+    pub fn from_json_str(json: &str) -> Result<Forecast> {
+        de::forecast_from_reader(json.as_bytes())
+    }
+
+    /// Parses a forecast from a reader of a JSON response body, such as a
+    /// cached file, like [`from_json_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`from_json_str`], as well as an
+    /// [`Error::Json`] if reading fails.
+    ///
+    /// [`Error::Json`]: enum.Error.html#variant.Json
+    /// [`from_json_str`]: #method.from_json_str
+    pub fn from_reader<R: Read>(reader: R) -> Result<Forecast> {
+        de::forecast_from_reader(reader)
+    }
+
+    /// The datapoints of the [minutely block][`minutely`], or an empty slice
+    /// if the block or its data is absent.
+    ///
+    /// [`minutely`]: #structfield.minutely
+    pub fn minutely_points(&self) -> &[Datapoint] {
+        self.minutely.as_ref().map(Datablock::points).unwrap_or(&[])
+    }
+
+    /// The datapoints of the [hourly block][`hourly`], or an empty slice if
+    /// the block or its data is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// fn print_hourly(forecast: &Forecast) {
+    ///     for datapoint in forecast.hourly_points() {
+    ///         println!("{}: {:?}", datapoint.time, datapoint.temperature);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`hourly`]: #structfield.hourly
+    pub fn hourly_points(&self) -> &[Datapoint] {
+        self.hourly.as_ref().map(Datablock::points).unwrap_or(&[])
+    }
+
+    /// The datapoints of the [daily block][`daily`], or an empty slice if the
+    /// block or its data is absent.
+    ///
+    /// [`daily`]: #structfield.daily
+    pub fn daily_points(&self) -> &[Datapoint] {
+        self.daily.as_ref().map(Datablock::points).unwrap_or(&[])
+    }
+
+    /// Whether two forecasts describe the same conditions, using the
+    /// [default tolerance][`DEFAULT_CONTENT_TOLERANCE`] for numeric values.
+    ///
+    /// Refer to [`content_eq_within`] for what is compared.
+    ///
+    /// [`DEFAULT_CONTENT_TOLERANCE`]: constant.DEFAULT_CONTENT_TOLERANCE.html
+    /// [`content_eq_within`]: #method.content_eq_within
+    pub fn content_eq(&self, other: &Forecast) -> bool {
+        self.content_eq_within(other, DEFAULT_CONTENT_TOLERANCE)
+    }
+
+    /// Whether two forecasts describe the same conditions, treating numeric
+    /// values as equal when they differ by no more than `tolerance`.
+    ///
+    /// This is useful for change detection, such as deciding whether a fresh
+    /// forecast needs to be re-rendered over a cached one.
+    ///
+    /// The location, alerts, and every datapoint's icon, summary, precipitation
+    /// type, and measurements are compared. Timestamps (such as
+    /// [`Datapoint::time`] and [`Alert::expires`]), the `*_error` confidence
+    /// fields, and the [`flags`] are ignored, as they vary between requests for
+    /// otherwise identical conditions.
+    ///
+    /// [`Alert::expires`]: struct.Alert.html#structfield.expires
+    /// [`Datapoint::time`]: struct.Datapoint.html#structfield.time
+    /// [`flags`]: #structfield.flags
+    pub fn content_eq_within(&self, other: &Forecast, tolerance: f64) -> bool {
+        let alerts_eq = self.alerts.len() == other.alerts.len()
+            && self.alerts.iter().zip(&other.alerts).all(|(a, b)| {
+                a.title == b.title && a.description == b.description && a.uri == b.uri
+            });
+
+        let currently_eq = match (self.currently.as_ref(), other.currently.as_ref()) {
+            (Some(a), Some(b)) => datapoint_content_eq(a, b, tolerance),
+            (None, None) => true,
+            _ => false,
+        };
+
+        approx_eq(self.latitude, other.latitude, tolerance)
+            && approx_eq(self.longitude, other.longitude, tolerance)
+            && self.timezone == other.timezone
+            && alerts_eq
+            && currently_eq
+            && datablock_content_eq(self.minutely.as_ref(), other.minutely.as_ref(), tolerance)
+            && datablock_content_eq(self.hourly.as_ref(), other.hourly.as_ref(), tolerance)
+            && datablock_content_eq(self.daily.as_ref(), other.daily.as_ref(), tolerance)
+    }
+
+    /// Estimates and fills in the [`apparent_temperature`] of every datapoint
+    /// where it is absent, but the temperature, humidity, and wind speed are
+    /// present. This is common in Time Machine data.
+    ///
+    /// The estimate uses the [`wind_chill`] in cold and windy conditions, the
+    /// [`heat_index`] in hot conditions, and otherwise the temperature itself.
+    /// Filled datapoints are marked with [`apparent_temperature_estimated`].
+    ///
+    /// The unit must be the one the forecast was fetched in. Nothing is filled
+    /// for [`Unit::Auto`], as the actual unit is not known.
+    ///
+    /// Returns the number of datapoints that were filled.
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`apparent_temperature`]: struct.Datapoint.html#structfield.apparent_temperature
+    /// [`apparent_temperature_estimated`]: struct.Datapoint.html#structfield.apparent_temperature_estimated
+    /// [`heat_index`]: fn.heat_index.html
+    /// [`wind_chill`]: fn.wind_chill.html
+    pub fn fill_apparent_temperature(&mut self, unit: Unit) -> usize {
+        fn fill(datapoint: &mut Datapoint, unit: Unit) -> bool {
+            if datapoint.apparent_temperature.is_some() {
+                return false;
+            }
+
+            let (temperature, humidity, wind_speed) = match (datapoint.temperature, datapoint.humidity, datapoint.wind_speed) {
+                (Some(t), Some(h), Some(w)) => (t, h, w),
+                _ => return false,
+            };
+
+            let (to_us, from_us) = match (meteorology::Conversion::new(unit, Unit::Us), meteorology::Conversion::new(Unit::Us, unit)) {
+                (Some(to_us), Some(from_us)) => (to_us, from_us),
+                _ => return false,
+            };
+
+            let fahrenheit = to_us.temperature(temperature);
+            let apparent = meteorology::wind_chill(fahrenheit, to_us.speed(wind_speed))
+                .or_else(|| meteorology::heat_index(fahrenheit, humidity))
+                .map(|apparent| from_us.temperature(apparent))
+                .unwrap_or(temperature);
+
+            datapoint.apparent_temperature = Some(apparent);
+            datapoint.apparent_temperature_estimated = true;
+
+            true
+        }
+
+        let mut filled = 0;
+
+        if let Some(ref mut currently) = self.currently {
+            filled += fill(currently, unit) as usize;
+        }
+
+        for block in [&mut self.minutely, &mut self.hourly, &mut self.daily] {
+            if let Some(data) = block.as_mut().and_then(|block| block.data.as_mut()) {
+                for datapoint in data {
+                    filled += fill(datapoint, unit) as usize;
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Checks that the [`currently`] datapoint is no older than the given
+    /// maximum age, relative to the system clock.
+    ///
+    /// This guards displays of safety-critical information against stale data,
+    /// such as from a misbehaving caching proxy. Forecasts without a current
+    /// datapoint, such as when it was excluded, are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleData`] with the datapoint's age if it is older
+    /// than `max_age`.
+    ///
+    /// [`Error::StaleData`]: enum.Error.html#variant.StaleData
+    /// [`currently`]: #structfield.currently
+    pub fn ensure_fresh(&self, max_age: Duration) -> Result<()> {
+        let time = match self.currently.as_ref().and_then(Datapoint::system_time) {
+            Some(time) => time,
+            None => return Ok(()),
+        };
+
+        match SystemTime::now().duration_since(time) {
+            Ok(age) if age > max_age => Err(Error::StaleData { age }),
+            _ => Ok(()),
+        }
+    }
+
+    /// The IANA [`timezone`] of the location, for converting timestamps to
+    /// local times.
+    ///
+    /// Returns `None` if the timezone is not in the timezone database.
+    ///
+    /// This requires the `timezones` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// fn local_time(forecast: &Forecast) -> Option<String> {
+    ///     let tz = forecast.timezone_tz()?;
+    ///     let time = forecast.currently.as_ref()?.time_utc()?;
+    ///
+    ///     Some(time.with_timezone(&tz).format("%H:%M").to_string())
+    /// }
+    /// ```
+    ///
+    /// [`timezone`]: #structfield.timezone
+    #[cfg(feature="timezones")]
+    pub fn timezone_tz(&self) -> Option<Tz> {
+        self.timezone.parse().ok()
+    }
+
+    /// The reported [`offset`] in whole seconds, for use with APIs which take
+    /// offsets in seconds, such as chrono's `FixedOffset`.
+    ///
+    /// Returns `None` if the offset is absent, or is not within a day of UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// let json = r#"{"latitude": 19.076, "longitude": 72.8777, "offset": 5.5, "timezone": "Asia/Kolkata"}"#;
+    /// let forecast = Forecast::from_json_str(json).unwrap();
+    ///
+    /// assert_eq!(forecast.utc_offset_seconds(), Some(19800));
+    /// ```
+    ///
+    /// [`offset`]: #structfield.offset
+    pub fn utc_offset_seconds(&self) -> Option<i32> {
+        let seconds = (self.offset? * 3600.0).round();
+
+        if seconds.abs() < 86400.0 {
+            Some(seconds as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the reported [`offset`] agrees with the offset of the IANA
+    /// [`timezone`] at the time of the [`currently`] datapoint, to within a
+    /// minute.
+    ///
+    /// The two occasionally disagree around daylight saving transitions, which
+    /// would otherwise produce wrong local times.
+    ///
+    /// Returns `None` if the offset, current datapoint, or a recognized
+    /// timezone is absent.
+    ///
+    /// This requires the `timezones` feature.
+    ///
+    /// [`currently`]: #structfield.currently
+    /// [`offset`]: #structfield.offset
+    /// [`timezone`]: #structfield.timezone
+    #[cfg(feature="timezones")]
+    pub fn offset_consistency(&self) -> Option<bool> {
+        let offset = self.utc_offset_seconds()?;
+        let time = self.currently.as_ref()?.time;
+        let tz = self.timezone_tz()?;
+
+        let utc = Utc.timestamp_opt(time as i64, 0).single()?;
+        let seconds = utc.with_timezone(&tz).offset().fix().local_minus_utc();
+
+        Some((offset - seconds).abs() < 60)
+    }
+
+    /// Returns a clone of the forecast with every measurement in every
+    /// datapoint converted from one unit type to another, and the units in the
+    /// [`flags`] updated to match.
+    ///
+    /// This is useful when a forecast was cached in one unit type, but is to be
+    /// displayed in another. Temperatures, speeds, distances, and
+    /// precipitation intensities and accumulations are converted, along with
+    /// their error margins. Pressures are left as-is, as millibars and
+    /// hectopascals are equal.
+    ///
+    /// Returns `None` if either unit type is [`Unit::Auto`], as the
+    /// measurement system it resolves to is not known.
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`flags`]: #structfield.flags
+    pub fn convert_units(&self, from: Unit, to: Unit) -> Option<Forecast> {
+        let conversion = meteorology::Conversion::new(from, to)?;
+        let mut forecast = self.clone();
+
+        {
+            let blocks = vec![
+                forecast.minutely.as_mut(),
+                forecast.hourly.as_mut(),
+                forecast.daily.as_mut(),
+            ];
+            let datapoints = blocks.into_iter()
+                .flatten()
+                .flat_map(|block| block.data.iter_mut().flatten())
+                .chain(forecast.currently.as_mut());
+
+            for datapoint in datapoints {
+                convert_datapoint(datapoint, &conversion);
+            }
+        }
+
+        if let Some(ref mut flags) = forecast.flags {
+            flags.units = Some(to.name().to_owned());
+        }
+
+        Some(forecast)
+    }
+
+    /// Retrieve the [`Datablock`] corresponding to the given [`Block`], if it
+    /// is present.
+    ///
+    /// [`Block::Daily`], [`Block::Hourly`], and [`Block::Minutely`] borrow the
+    /// respective datablock. [`Block::Currently`] returns a synthesized,
+    /// owned datablock containing only the [`currently`] datapoint, with its
+    /// icon and summary. [`Block::Alerts`] and [`Block::Flags`] always return
+    /// `None`, as the alerts and flags are not datablocks.
+    ///
+    /// [`Block`]: enum.Block.html
+    /// [`Block::Alerts`]: enum.Block.html#variant.Alerts
+    /// [`Block::Currently`]: enum.Block.html#variant.Currently
+    /// [`Block::Daily`]: enum.Block.html#variant.Daily
+    /// [`Block::Flags`]: enum.Block.html#variant.Flags
+    /// [`Block::Hourly`]: enum.Block.html#variant.Hourly
+    /// [`Block::Minutely`]: enum.Block.html#variant.Minutely
+    /// [`Datablock`]: struct.Datablock.html
+    /// [`currently`]: #structfield.currently
+    pub fn block<'a>(&'a self, block: Block) -> Option<Cow<'a, Datablock>> {
+        match block {
+            Block::Alerts | Block::Flags => None,
+            Block::Currently => self.currently.as_ref().map(|currently| {
+                Cow::Owned(Datablock {
+                    data: Some(vec![currently.clone()]),
+                    icon: currently.icon,
+                    summary: currently.summary.clone(),
+                })
+            }),
+            Block::Daily => self.daily.as_ref().map(Cow::Borrowed),
+            Block::Hourly => self.hourly.as_ref().map(Cow::Borrowed),
+            Block::Minutely => self.minutely.as_ref().map(Cow::Borrowed),
+        }
+    }
+
+    /// Returns a clone of the forecast with the given blocks removed, mirroring
+    /// the API's `exclude` parameter for forecasts which have already been
+    /// retrieved, such as those loaded from a cache or an archive.
+    ///
+    /// Prefer [`Options::exclude`] when fetching, which avoids transferring the
+    /// excluded blocks at all.
+    ///
+    /// [`Options::exclude`]: struct.Options.html#method.exclude
+    pub fn without_blocks(&self, blocks: &[Block]) -> Forecast {
+        let mut forecast = self.clone();
+
+        for block in blocks {
+            match *block {
+                Block::Alerts => forecast.alerts.clear(),
+                Block::Currently => forecast.currently = None,
+                Block::Daily => forecast.daily = None,
+                Block::Flags => forecast.flags = None,
+                Block::Hourly => forecast.hourly = None,
+                Block::Minutely => forecast.minutely = None,
+            }
+        }
+
+        forecast
+    }
+
+    /// Whether the units reported in the forecast's [`flags`] are those that
+    /// were requested, to catch requests whose unit parameter was silently
+    /// ignored.
+    ///
+    /// The API resolves [`Unit::Auto`] to a concrete unit type based on the
+    /// location, so any reported unit matches it. Returns `false` if the flags
+    /// or their units are absent.
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`flags`]: #structfield.flags
+    pub fn unit_matches(&self, requested: Unit) -> bool {
+        let units = match self.flags.as_ref().and_then(|flags| flags.units.as_ref()) {
+            Some(units) => units,
+            None => return false,
+        };
+
+        match requested {
+            Unit::Auto => true,
+            requested => units == requested.name(),
+        }
+    }
+
+    /// The unit type the forecast's values are in, as reported in its
+    /// [`flags`], such as the unit type that [`Unit::Auto`] resolved to for
+    /// the location.
+    ///
+    /// Returns `None` if the flags or their units are absent, or if the units
+    /// are not recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::{Forecast, Unit};
+    ///
+    /// fn temperature_label(forecast: &Forecast) -> &'static str {
+    ///     match forecast.resolved_unit() {
+    ///         Some(Unit::Us) => "°F",
+    ///         Some(_) => "°C",
+    ///         None => "°",
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+    /// [`flags`]: #structfield.flags
+    pub fn resolved_unit(&self) -> Option<Unit> {
+        let units = self.flags.as_ref()?.units.as_ref()?;
+
+        [Unit::Ca, Unit::Si, Unit::Uk2, Unit::Us]
+            .iter()
+            .find(|unit| unit.name() == units)
+            .cloned()
+    }
+
+    /// The highest [`precip_probability`] for today, taken from the first
+    /// datapoint of the [daily block][`daily`] along with any datapoints of the
+    /// [hourly block][`hourly`] within that day.
+    ///
+    /// If the daily block is absent, today is taken to be the 24 hours from the
+    /// first hourly datapoint.
+    ///
+    /// Returns `None` if no precipitation probabilities for today are present.
+    ///
+    /// [`daily`]: #structfield.daily
+    /// [`hourly`]: #structfield.hourly
+    /// [`precip_probability`]: struct.Datapoint.html#structfield.precip_probability
+    pub fn max_precip_probability_today(&self) -> Option<f64> {
+        let today = self.daily_points().first();
+        let hourly = self.hourly_points();
+
+        let start = match today {
+            Some(today) => today.time,
+            None => hourly.first()?.time,
+        };
+        let end = start + 24 * 60 * 60;
+
+        hourly.iter()
+            .filter(|datapoint| datapoint.time >= start && datapoint.time < end)
+            .chain(today)
+            .filter_map(|datapoint| datapoint.precip_probability)
+            .fold(None, |max, probability| match max {
+                Some(max) if max >= probability => Some(max),
+                _ => Some(probability),
+            })
+    }
+
+    /// Whether rain is expected today, being whether the
+    /// [highest precipitation probability today][`max_precip_probability_today`]
+    /// is at or above the given threshold.
+    ///
+    /// Returns `None` if no precipitation probabilities for today are present.
+    ///
+    /// [`max_precip_probability_today`]: #method.max_precip_probability_today
+    pub fn rain_expected_today(&self, threshold: f64) -> Option<bool> {
+        self.max_precip_probability_today().map(|probability| probability >= threshold)
+    }
+
+    /// The lowest [`temperature_min`] and highest [`temperature_max`] across
+    /// the [daily block][`daily`], as a `(low, high)` pair for the week.
+    ///
+    /// Datapoints missing either field are skipped for that field, rather than
+    /// treated as zero.
+    ///
+    /// Returns `None` if the daily block is absent, or has no minimum or no
+    /// maximum temperatures.
+    ///
+    /// [`daily`]: #structfield.daily
+    /// [`temperature_max`]: struct.Datapoint.html#structfield.temperature_max
+    /// [`temperature_min`]: struct.Datapoint.html#structfield.temperature_min
+    pub fn weekly_temperature_range(&self) -> Option<(f64, f64)> {
+        let daily = self.daily_points();
+
+        // `f64::min` and `f64::max` return the other operand when one is NaN,
+        // so the result is only NaN if there were no temperatures at all.
+        let low = daily.iter()
+            .filter_map(|datapoint| datapoint.temperature_min)
+            .fold(f64::NAN, f64::min);
+        let high = daily.iter()
+            .filter_map(|datapoint| datapoint.temperature_max)
+            .fold(f64::NAN, f64::max);
+
+        if low.is_nan() || high.is_nan() {
+            None
+        } else {
+            Some((low, high))
+        }
+    }
+
+    /// The minute-by-minute [`precip_intensity`] over the next hour, as
+    /// `(time, intensity)` pairs from the [minutely block][`minutely`].
+    ///
+    /// Minutes without an intensity are skipped. The result is empty if the
+    /// minutely block is absent, such as outside of its coverage area.
+    ///
+    /// # Examples
+    ///
+    /// Find how many minutes until rain starts:
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// fn minutes_until_rain(forecast: &Forecast, now: u64) -> Option<u64> {
+    ///     forecast.next_hour_precipitation()
+    ///         .into_iter()
+    ///         .find(|&(_, intensity)| intensity > 0.0)
+    ///         .map(|(time, _)| time.saturating_sub(now) / 60)
+    /// }
+    /// ```
+    ///
+    /// [`minutely`]: #structfield.minutely
+    /// [`precip_intensity`]: struct.Datapoint.html#structfield.precip_intensity
+    pub fn next_hour_precipitation(&self) -> Vec<(u64, f64)> {
+        self.minutely_points()
+            .iter()
+            .filter_map(|datapoint| datapoint.precip_intensity.map(|intensity| (datapoint.time, intensity)))
+            .collect()
+    }
+
+    /// The sunrise, sunset, and golden and blue hours of the first day in the
+    /// [daily block][`daily`].
+    ///
+    /// Returns `None` if the daily block is absent, or if the sun doesn't rise
+    /// or set that day, such as during polar day or night, which lasts for
+    /// months at the poles. Near the poles, a sunset reported before the
+    /// sunrise is also treated as degenerate and returns `None`.
+    ///
+    /// [`daily`]: #structfield.daily
+    pub fn sun_events(&self) -> Option<SunEvents> {
+        let today = self.daily.as_ref()?.data.as_ref()?.first()?;
+
+        match (today.sunrise_time, today.sunset_time) {
+            (Some(sunrise), Some(sunset)) if sunrise <= sunset => {
+                Some(SunEvents::new(sunrise, sunset))
+            },
+            _ => None,
+        }
+    }
+
+    /// Calculates the great-circle distance in kilometers from the forecast's
+    /// location to the given point.
+    ///
+    /// This is well-defined everywhere, including at the poles and between
+    /// antipodal points.
+    pub fn distance_to(&self, latitude: f64, longitude: f64) -> f64 {
+        geo::haversine_km(self.latitude, self.longitude, latitude, longitude)
+    }
+
+    /// Calculates the great-circle distance in miles from the forecast's
+    /// location to the given point.
+    pub fn distance_to_miles(&self, latitude: f64, longitude: f64) -> f64 {
+        self.distance_to(latitude, longitude) / geo::KM_PER_MILE
+    }
+
+    /// Calculates the initial compass direction from the forecast's location
+    /// to the given point.
+    ///
+    /// The bearing is singular at the poles, where the direction to every
+    /// other point is due south from the North Pole, or due north from the
+    /// South Pole. From a pole, this is therefore always
+    /// [`CompassDirection::S`] or [`CompassDirection::N`] respectively. The
+    /// direction to the location itself is [`CompassDirection::N`] elsewhere.
+    ///
+    /// [`CompassDirection::N`]: enum.CompassDirection.html#variant.N
+    /// [`CompassDirection::S`]: enum.CompassDirection.html#variant.S
+    pub fn bearing_to(&self, latitude: f64, longitude: f64) -> CompassDirection {
+        let degrees = geo::initial_bearing(self.latitude, self.longitude, latitude, longitude);
+
+        CompassDirection::from_bearing(degrees)
+    }
+
+    /// Consumes the forecast, returning its blocks and location as owned
+    /// [parts][`ForecastParts`].
+    ///
+    /// This allows moving individual blocks elsewhere without cloning them.
+    ///
+    /// [`ForecastParts`]: struct.ForecastParts.html
+    pub fn into_parts(self) -> ForecastParts {
+        ForecastParts {
+            alerts: self.alerts,
+            currently: self.currently,
+            daily: self.daily,
+            flags: self.flags,
+            hourly: self.hourly,
+            location: Location {
+                latitude: self.latitude,
+                longitude: self.longitude,
+                offset: self.offset,
+                timezone: self.timezone,
+            },
+            minutely: self.minutely,
+        }
+    }
+}
+
+/// The owned components of a [`Forecast`], as returned by
+/// [`Forecast::into_parts`].
+///
+/// [`Forecast`]: struct.Forecast.html
+/// [`Forecast::into_parts`]: struct.Forecast.html#method.into_parts
+#[derive(Clone, Debug)]
+pub struct ForecastParts {
+    /// Severe weather alerts issued for the location.
+    pub alerts: Vec<Alert>,
+    /// The current forecast.
+    pub currently: Option<Datapoint>,
+    /// Daily [`Datablock`]s within the forecast.
+    ///
+    /// [`Datablock`]: struct.Datablock.html
+    pub daily: Option<Datablock>,
+    /// The set of flags returned from the API.
+    pub flags: Option<Flags>,
+    /// Hourly [`Datablock`]s within the forecast.
+    ///
+    /// [`Datablock`]: struct.Datablock.html
+    pub hourly: Option<Datablock>,
+    /// The location of the forecast.
+    pub location: Location,
+    /// Minutely [`Datablock`]s within the forecast.
+    ///
+    /// [`Datablock`]: struct.Datablock.html
     pub minutely: Option<Datablock>,
-    /// The timezone offset of the forecast, relative to the UTC timezone.
+}
+
+/// A [`Forecast`] along with metadata from the headers of the response it was
+/// retrieved in, as returned by [`DarkskyRequester::get_forecast_with_meta`].
+///
+/// [`DarkskyRequester::get_forecast_with_meta`]: trait.DarkskyRequester.html#method.get_forecast_with_meta
+/// [`Forecast`]: struct.Forecast.html
+#[derive(Clone, Debug)]
+pub struct ForecastResponse {
+    /// The number of calls made against the token today, including this one,
+    /// from the `X-Forecast-API-Calls` header.
+    ///
+    /// This can be compared against the
+    /// [free daily call limit][`FREE_DAILY_CALL_LIMIT`] to monitor the token's
+    /// quota.
+    ///
+    /// [`FREE_DAILY_CALL_LIMIT`]: limits/constant.FREE_DAILY_CALL_LIMIT.html
+    pub api_calls: Option<u32>,
+    /// Whether the forecast was served from the API's cache rather than
+    /// freshly computed, from the `X-Forecast-Cache` header.
+    ///
+    /// The header may be absent on some responses, such as those for time
+    /// machine requests, in which case this is `None`.
+    pub cache_hit: Option<bool>,
+    /// The forecast.
+    pub forecast: Forecast,
+    /// The time taken by the API to produce the response in milliseconds,
+    /// from the `X-Response-Time` header.
+    pub response_time_ms: Option<u32>,
+}
+
+/// A [`Forecast`] along with the raw JSON it was deserialized from, for
+/// inspection via [`raw_pointer`].
+///
+/// This requires the `raw-json` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::RawForecast;
+///
+/// let json = r#"{"latitude": 37.8267, "longitude": -122.423, "timezone": "America/Los_Angeles", "elevation": 12}"#;
+/// let raw = RawForecast::from_json_str(json).unwrap();
+///
+/// assert_eq!(raw.forecast.timezone, "America/Los_Angeles");
+/// assert_eq!(raw.raw_pointer("/elevation").and_then(|value| value.as_u64()), Some(12));
+/// ```
+///
+/// [`Forecast`]: struct.Forecast.html
+/// [`raw_pointer`]: #method.raw_pointer
+#[cfg(feature="raw-json")]
+#[derive(Clone, Debug)]
+pub struct RawForecast {
+    /// The forecast.
+    pub forecast: Forecast,
+    /// The JSON the forecast was deserialized from.
+    pub raw: Value,
+}
+
+#[cfg(feature="raw-json")]
+impl RawForecast {
+    /// Parses a forecast from a JSON response body like
+    /// [`Forecast::from_json_str`], retaining the JSON alongside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Forecast::from_json_str`].
+    ///
+    /// [`Forecast::from_json_str`]: struct.Forecast.html#method.from_json_str
+    pub fn from_json_str(json: &str) -> Result<RawForecast> {
+        RawForecast::from_reader(json.as_bytes())
+    }
+
+    /// Parses a forecast from a reader of a JSON response body like
+    /// [`Forecast::from_reader`], retaining the JSON alongside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Forecast::from_reader`].
+    ///
+    /// [`Forecast::from_reader`]: struct.Forecast.html#method.from_reader
+    pub fn from_reader<R: Read>(reader: R) -> Result<RawForecast> {
+        let (forecast, raw) = de::forecast_from_reader_with_raw(reader)?;
+
+        Ok(RawForecast {
+            forecast,
+            raw,
+        })
+    }
+
+    /// Looks up a value in the raw JSON by a [JSON Pointer], such as
+    /// `/hourly/data/3/temperature`.
+    ///
+    /// This is useful for reconciling parsed values against the response when
+    /// investigating changes to the API's schema, including fields which are
+    /// not modeled. Returns `None` if nothing is found at the pointer.
+    ///
+    /// [JSON Pointer]: https://tools.ietf.org/html/rfc6901
+    pub fn raw_pointer(&self, pointer: &str) -> Option<&Value> {
+        self.raw.pointer(pointer)
+    }
+}
+
+/// The location of a [`Forecast`], as part of its [`ForecastParts`].
+///
+/// [`Forecast`]: struct.Forecast.html
+/// [`ForecastParts`]: struct.ForecastParts.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    /// The latitude of the location.
+    pub latitude: f64,
+    /// The longitude of the location.
+    pub longitude: f64,
+    /// The timezone offset of the location, relative to the UTC timezone.
     pub offset: Option<f64>,
-    /// The name of the timezone.
+    /// The name of the timezone of the location.
     pub timezone: String,
 }
+
+/// A validated pair of latitude and longitude, in degrees.
+///
+/// Longitudes outside of `[-180, 180)` are wrapped into that range, as they
+/// refer to valid points across the antimeridian: `181` becomes `-179`, and
+/// both `180` and `540` become `-180`. This suits map panning, which can
+/// produce out-of-range longitudes. Latitudes are not wrapped.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Coordinate {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Coordinate {
+    /// Creates a coordinate, wrapping the longitude into `[-180, 180)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(37.8267, 181.0).unwrap();
+    ///
+    /// assert_eq!(coordinate.longitude(), -179.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLocation`] if the latitude is outside of
+    /// `[-90, 90]`, or if either value is not a finite number.
+    ///
+    /// [`Error::InvalidLocation`]: enum.Error.html#variant.InvalidLocation
+    pub fn new(latitude: f64, longitude: f64) -> Result<Coordinate> {
+        if !latitude.is_finite() || !longitude.is_finite() || latitude.abs() > 90.0 {
+            return Err(Error::InvalidLocation(latitude, longitude));
+        }
+
+        Ok(Coordinate {
+            latitude,
+            longitude: ((longitude + 180.0) % 360.0 + 360.0) % 360.0 - 180.0,
+        })
+    }
+
+    /// The latitude, within `[-90, 90]`.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// The longitude, within `[-180, 180)`.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+}
+
+/// Converts each measurement of the datapoint in place.
+fn convert_datapoint(datapoint: &mut Datapoint, conversion: &meteorology::Conversion) {
+    fn convert<F: Fn(f64) -> f64>(fields: &mut [&mut Option<f64>], f: F) {
+        for field in fields {
+            if let Some(ref mut value) = **field {
+                *value = f(*value);
+            }
+        }
+    }
+
+    convert(&mut [
+        &mut datapoint.apparent_temperature,
+        &mut datapoint.apparent_temperature_max,
+        &mut datapoint.apparent_temperature_min,
+        &mut datapoint.dew_point,
+        &mut datapoint.temperature,
+        &mut datapoint.temperature_max,
+        &mut datapoint.temperature_min,
+    ], |value| conversion.temperature(value));
+    convert(&mut [
+        &mut datapoint.dew_point_error,
+        &mut datapoint.temperature_error,
+        &mut datapoint.temperature_max_error,
+        &mut datapoint.temperature_min_error,
+    ], |value| conversion.temperature_difference(value));
+    convert(&mut [
+        &mut datapoint.wind_gust,
+        &mut datapoint.wind_speed,
+        &mut datapoint.wind_speed_error,
+    ], |value| conversion.speed(value));
+    convert(&mut [
+        &mut datapoint.nearest_storm_distance,
+        &mut datapoint.visibility,
+        &mut datapoint.visibility_error,
+    ], |value| conversion.distance(value));
+    convert(&mut [
+        &mut datapoint.precip_intensity,
+        &mut datapoint.precip_intensity_error,
+        &mut datapoint.precip_intensity_max,
+        &mut datapoint.precip_intensity_max_error,
+    ], |value| conversion.precip_intensity(value));
+    convert(&mut [
+        &mut datapoint.precip_accumulation,
+        &mut datapoint.precip_accumulation_error,
+    ], |value| conversion.precip_accumulation(value));
+}
+
+/// Converts a [Unix timestamp][unixtime], such as any of the `*_time` fields
+/// of a [`Datapoint`], into a `SystemTime`.
+///
+/// Returns `None` if the timestamp can not be represented on this platform,
+/// rather than panicking.
+///
+/// [`Datapoint`]: struct.Datapoint.html
+/// [unixtime]: https://en.wikipedia.org/wiki/Unix_time
+pub fn unix_to_system_time(seconds: u64) -> Option<SystemTime> {
+    UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
+
+/// Converts a [Unix timestamp][unixtime], such as any of the `*_time` fields
+/// of a [`Datapoint`], into a UTC `DateTime`.
+///
+/// Returns `None` if the timestamp is out of chrono's range, rather than
+/// panicking.
+///
+/// This requires the `chrono` feature.
+///
+/// [`Datapoint`]: struct.Datapoint.html
+/// [unixtime]: https://en.wikipedia.org/wiki/Unix_time
+#[cfg(feature="chrono")]
+pub fn unix_to_utc(seconds: u64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(i64::try_from(seconds).ok()?, 0).single()
+}
+
+fn approx_eq(a: f64, b: f64, tolerance: f64) -> bool {
+    (a - b).abs() <= tolerance
+}
+
+fn approx_option_eq(a: Option<f64>, b: Option<f64>, tolerance: f64) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => approx_eq(a, b, tolerance),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn datablock_content_eq(a: Option<&Datablock>, b: Option<&Datablock>, tolerance: f64) -> bool {
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        (None, None) => return true,
+        _ => return false,
+    };
+
+    let data_eq = match (a.data.as_ref(), b.data.as_ref()) {
+        (Some(a), Some(b)) => {
+            a.len() == b.len()
+                && a.iter().zip(b).all(|(a, b)| datapoint_content_eq(a, b, tolerance))
+        },
+        (None, None) => true,
+        _ => false,
+    };
+
+    a.icon == b.icon && a.summary == b.summary && data_eq
+}
+
+fn datapoint_content_eq(a: &Datapoint, b: &Datapoint, tolerance: f64) -> bool {
+    let measurements = [
+        (a.apparent_temperature, b.apparent_temperature),
+        (a.apparent_temperature_max, b.apparent_temperature_max),
+        (a.apparent_temperature_min, b.apparent_temperature_min),
+        (a.cloud_cover, b.cloud_cover),
+        (a.dew_point, b.dew_point),
+        (a.humidity, b.humidity),
+        (a.moon_phase, b.moon_phase),
+        (a.nearest_storm_bearing, b.nearest_storm_bearing),
+        (a.nearest_storm_distance, b.nearest_storm_distance),
+        (a.ozone, b.ozone),
+        (a.precip_accumulation, b.precip_accumulation),
+        (a.precip_intensity, b.precip_intensity),
+        (a.precip_intensity_max, b.precip_intensity_max),
+        (a.precip_probability, b.precip_probability),
+        (a.pressure, b.pressure),
+        (a.temperature, b.temperature),
+        (a.temperature_max, b.temperature_max),
+        (a.temperature_min, b.temperature_min),
+        (a.uv_index, b.uv_index),
+        (a.visibility, b.visibility),
+        (a.wind_bearing, b.wind_bearing),
+        (a.wind_gust, b.wind_gust),
+        (a.wind_speed, b.wind_speed),
+    ];
+
+    a.icon == b.icon
+        && a.precip_type == b.precip_type
+        && a.summary == b.summary
+        && measurements.iter().all(|&(a, b)| approx_option_eq(a, b, tolerance))
+}
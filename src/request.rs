@@ -0,0 +1,155 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! A builder for forecast requests, naming each of their parts.
+
+use models::Forecast;
+use {build_uri_at, DarkskyRequester, Error, Options, Result};
+
+/// A builder for a forecast or [Time Machine][time machine] request, as an
+/// alternative to the positional arguments of the [`DarkskyRequester`]
+/// methods.
+///
+/// The latitude and longitude are given together via [`location`], so they
+/// can not be confused with the token or each other's position among
+/// unrelated arguments. The whole request is validated before it is sent.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::{Block, Request, SimulatedTransport, Unit};
+///
+/// let transport = SimulatedTransport::new(1);
+///
+/// let forecast = Request::new("token")
+///     .location(37.8267, -122.423)
+///     .options(|o| o.exclude(vec![Block::Minutely]).unit(Unit::Si))
+///     .send(&transport)
+///     .unwrap();
+///
+/// assert!(forecast.minutely.is_none());
+/// ```
+///
+/// [`DarkskyRequester`]: trait.DarkskyRequester.html
+/// [`location`]: #method.location
+/// [time machine]: https://darksky.net/dev/docs#time-machine-request
+#[derive(Clone, Debug)]
+pub struct Request {
+    location: Option<(f64, f64)>,
+    options: Options,
+    time: Option<u64>,
+    token: String,
+}
+
+impl Request {
+    /// Creates a request made with the given API token, with no location and
+    /// no options set.
+    pub fn new<S: Into<String>>(token: S) -> Request {
+        Request {
+            location: None,
+            options: Options::default(),
+            time: None,
+            token: token.into(),
+        }
+    }
+
+    /// Sets the latitude and longitude to request the weather for.
+    pub fn location(mut self, latitude: f64, longitude: f64) -> Self {
+        self.location = Some((latitude, longitude));
+
+        self
+    }
+
+    /// Sets the options of the request, such as the [`Unit`] or
+    /// [`Block`]s to exclude, replacing any set previously. For a full list
+    /// of options, refer to the documentation for the [`Options`] builder.
+    ///
+    /// [`Block`]: enum.Block.html
+    /// [`Options`]: struct.Options.html
+    /// [`Unit`]: enum.Unit.html
+    pub fn options<F>(mut self, options: F) -> Self
+        where F: FnOnce(Options) -> Options {
+        self.options = options(Options::default());
+
+        self
+    }
+
+    /// Makes the request a [Time Machine][time machine] request for the
+    /// weather at a point in time, as a Unix timestamp.
+    ///
+    /// [time machine]: https://darksky.net/dev/docs#time-machine-request
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Some(time);
+
+        self
+    }
+
+    /// Builds the URI that [`send`] would request, such as for logging.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOptions`] if no [`location`] was given.
+    ///
+    /// Otherwise returns the same errors as [`build_uri`], or as
+    /// [`build_time_machine_uri`] if a [`time`] was given.
+    ///
+    /// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+    /// [`build_time_machine_uri`]: fn.build_time_machine_uri.html
+    /// [`build_uri`]: fn.build_uri.html
+    /// [`location`]: #method.location
+    /// [`send`]: #method.send
+    /// [`time`]: #method.time
+    pub fn uri(&self) -> Result<String> {
+        let (latitude, longitude) = self.coordinates()?;
+
+        build_uri_at(&self.token, latitude, longitude, self.time, &self.options)
+    }
+
+    /// Sends the request with the given requester, such as a hyper `Client`,
+    /// via [`get_forecast_with_opts`] or, if a [`time`] was given,
+    /// [`get_time_machine_with_options`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`uri`] without sending the request if it is
+    /// invalid, and otherwise any error returned by the requester.
+    ///
+    /// [`get_forecast_with_opts`]: trait.DarkskyRequester.html#method.get_forecast_with_opts
+    /// [`get_time_machine_with_options`]: trait.DarkskyRequester.html#method.get_time_machine_with_options
+    /// [`time`]: #method.time
+    /// [`uri`]: #method.uri
+    pub fn send<R: DarkskyRequester>(self, requester: &R) -> Result<Forecast> {
+        self.uri()?;
+
+        let (latitude, longitude) = self.coordinates()?;
+        let options = self.options;
+
+        match self.time {
+            Some(time) => requester.get_time_machine_with_options(
+                &self.token,
+                latitude,
+                longitude,
+                time,
+                |_| options,
+            ),
+            None => requester.get_forecast_with_opts(&self.token, latitude, longitude, options),
+        }
+    }
+
+    fn coordinates(&self) -> Result<(f64, f64)> {
+        self.location.ok_or(Error::InvalidOptions("no location was given"))
+    }
+}
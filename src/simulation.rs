@@ -0,0 +1,642 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Offline generation of synthetic forecasts, for demos and UI development.
+
+use limits;
+use models::{Coordinate, Datablock, Datapoint, Flags, Forecast, Icon, PrecipitationType};
+use serde_json;
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use transport::{HttpTransport, TransportResponse};
+use {Block, Result, Unit, API_URL};
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+/// The time over which a simulated weather system passes, bringing smooth
+/// changes in precipitation and cloud cover.
+const SYSTEM_LENGTH: u64 = 6 * HOUR;
+
+/// The length of a synodic month, from new moon to new moon, in days.
+const LUNATION_DAYS: f64 = 29.530_588_853;
+
+/// The time of a known new moon, on 2000-01-06.
+const NEW_MOON: f64 = 947_182_440.0;
+
+/// An [`HttpTransport`] which generates plausible synthetic forecasts for any
+/// location offline, rather than requesting them from the API.
+///
+/// As with any transport, it is used through [`DarkskyRequester`], so request
+/// URIs are built and validated, and responses are checked and parsed, just
+/// as for a real HTTP client.
+///
+/// This is intended for demos and UI development without a token or network
+/// access. Forecasts have a smooth daily temperature curve, precipitation and
+/// cloud cover from coherent weather systems passing over several hours, and
+/// sunrises and sunsets calculated from the location and date. They are
+/// generated in [US units][`Unit::Us`] and converted to the unit set with
+/// [`Options::unit`], with [`Unit::Auto`] treated as US units. Summaries are
+/// always in English, and there are never any alerts.
+///
+/// The token is not checked, other than that it must not be empty, as for
+/// any request. Generation is deterministic for a given seed, location, and
+/// time, so a demo can be reproduced exactly by also fixing the current time
+/// with [`at`].
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::{DarkskyRequester, SimulatedTransport};
+///
+/// let transport = SimulatedTransport::new(42).at(1509993277);
+/// let forecast = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+///
+/// assert_eq!(forecast.currently.map(|currently| currently.time), Some(1509993277));
+/// ```
+///
+/// [`DarkskyRequester`]: trait.DarkskyRequester.html
+/// [`HttpTransport`]: trait.HttpTransport.html
+/// [`Options::unit`]: struct.Options.html#method.unit
+/// [`Unit::Auto`]: enum.Unit.html#variant.Auto
+/// [`Unit::Us`]: enum.Unit.html#variant.Us
+/// [`at`]: #method.at
+#[derive(Clone, Debug)]
+pub struct SimulatedTransport {
+    seed: u64,
+    time: Option<u64>,
+}
+
+impl SimulatedTransport {
+    /// Creates a transport generating forecasts from the given seed.
+    pub fn new(seed: u64) -> SimulatedTransport {
+        SimulatedTransport {
+            seed,
+            time: None,
+        }
+    }
+
+    /// Fixes the current time of generated forecasts, as a Unix timestamp,
+    /// rather than using the system clock.
+    pub fn at(mut self, time: u64) -> Self {
+        self.time = Some(time);
+
+        self
+    }
+
+    fn now(&self) -> u64 {
+        self.time.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0)
+        })
+    }
+
+    /// Generates a forecast for the request, at the current time or, for a
+    /// Time Machine request, for the day of its time.
+    fn generate(&self, request: &SimulatedRequest) -> Result<Forecast> {
+        let coordinate = Coordinate::new(request.latitude, request.longitude)?;
+        let site = Site {
+            seed: self.seed,
+            latitude: coordinate.latitude(),
+            longitude: coordinate.longitude(),
+        };
+
+        let time_machine = request.time.is_some();
+        let time = request.time.unwrap_or_else(|| self.now());
+        let param = |key: &str| request.query.get(key).map(|value| &value[..]);
+
+        let excluded = |block: Block| match param("exclude") {
+            Some(list) => list.split(',').any(|name| name == block.name()),
+            None => false,
+        };
+        let extended = param("extend") == Some("hourly");
+        let unit = [Unit::Ca, Unit::Si, Unit::Uk2]
+            .iter()
+            .find(|unit| param("units") == Some(unit.name()))
+            .cloned()
+            .unwrap_or(Unit::Us);
+
+        let midnight = site.midnight(time);
+
+        let (minutely, hourly, daily) = if time_machine {
+            (vec![], site.hours(midnight, 24), site.days(midnight, 1))
+        } else {
+            let hours = if extended {
+                limits::EXTENDED_HOURLY_HOURS
+            } else {
+                limits::HOURLY_HOURS
+            };
+
+            (
+                site.minutes(time - time % 60, u64::from(limits::MINUTELY_MINUTES) + 1),
+                site.hours(time - time % HOUR, u64::from(hours) + 1),
+                site.days(midnight, u64::from(limits::FORECAST_DAYS) + 1),
+            )
+        };
+
+        let mut forecast = Forecast {
+            alerts: vec![],
+            currently: if excluded(Block::Currently) {
+                None
+            } else {
+                Some(site.datapoint(time))
+            },
+            daily: if excluded(Block::Daily) {
+                None
+            } else {
+                Some(datablock(daily, "throughout the week"))
+            },
+            extra: BTreeMap::new(),
+            flags: if excluded(Block::Flags) {
+                None
+            } else {
+                Some(Flags {
+                    sources: Some(vec!["simulated".to_owned()]),
+                    units: Some(Unit::Us.name().to_owned()),
+                    ..Flags::default()
+                })
+            },
+            hourly: if excluded(Block::Hourly) {
+                None
+            } else {
+                Some(datablock(hourly, "throughout the day"))
+            },
+            latitude: site.latitude,
+            longitude: site.longitude,
+            minutely: if excluded(Block::Minutely) || minutely.is_empty() {
+                None
+            } else {
+                Some(datablock(minutely, "for the hour"))
+            },
+            offset: Some(site.offset() as f64),
+            timezone: site.timezone(),
+        };
+
+        forecast.fill_apparent_temperature(Unit::Us);
+
+        Ok(match forecast.convert_units(Unit::Us, unit) {
+            Some(converted) => converted,
+            None => forecast,
+        })
+    }
+}
+
+impl HttpTransport for SimulatedTransport {
+    /// Generates a forecast for a URI built by [`build_uri`] or
+    /// [`build_time_machine_uri`], responding as the API would.
+    ///
+    /// A URI which is not for a forecast is responded to with a `400` status.
+    ///
+    /// [`build_time_machine_uri`]: fn.build_time_machine_uri.html
+    /// [`build_uri`]: fn.build_uri.html
+    fn get(&self, uri: &str) -> Result<TransportResponse> {
+        let request = match SimulatedRequest::parse(uri) {
+            Some(request) => request,
+            None => {
+                return Ok(TransportResponse {
+                    body: br#"{"code":400,"error":"The request is not for a forecast."}"#.to_vec(),
+                    headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+                    status: 400,
+                });
+            },
+        };
+
+        let mut response = TransportResponse::ok(serde_json::to_vec(&self.generate(&request)?)?);
+        response.headers.push(("Content-Type".to_owned(), "application/json".to_owned()));
+
+        Ok(response)
+    }
+
+    /// Generates a forecast like [`get`], which responds immediately and so
+    /// always within the timeout.
+    ///
+    /// [`get`]: #method.get
+    fn get_with_timeout(&self, uri: &str, _timeout: Duration) -> Result<TransportResponse> {
+        self.get(uri)
+    }
+}
+
+/// The parts of a forecast or Time Machine request URI.
+struct SimulatedRequest {
+    latitude: f64,
+    longitude: f64,
+    query: BTreeMap<String, String>,
+    time: Option<u64>,
+}
+
+impl SimulatedRequest {
+    /// Parses a URI of the form
+    /// `{API_URL}/forecast/{token}/{latitude},{longitude}[,{time}][?{query}]`.
+    fn parse(uri: &str) -> Option<SimulatedRequest> {
+        let rest = uri.get(API_URL.len()..).filter(|_| uri.starts_with(API_URL))?;
+        let rest = rest.get("/forecast/".len()..).filter(|_| rest.starts_with("/forecast/"))?;
+        let (path, query) = match rest.find('?') {
+            Some(index) => (&rest[..index], &rest[index + 1..]),
+            None => (rest, ""),
+        };
+
+        let mut parts = path.rsplit('/').next()?.split(',');
+        let latitude = parts.next()?.parse().ok()?;
+        let longitude = parts.next()?.parse().ok()?;
+        let time = match parts.next() {
+            Some(time) => Some(time.parse().ok()?),
+            None => None,
+        };
+
+        let query = query.split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let mut pair = pair.splitn(2, '=');
+                let key = decode(pair.next().unwrap_or(""));
+                let value = decode(pair.next().unwrap_or(""));
+
+                (key, value)
+            })
+            .collect();
+
+        Some(SimulatedRequest {
+            latitude,
+            longitude,
+            query,
+            time,
+        })
+    }
+}
+
+/// Decodes a percent-encoded query key or value, leaving malformed escapes
+/// as they are.
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped = component.get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            },
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// A location being simulated, with the seed of its weather.
+struct Site {
+    seed: u64,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Site {
+    /// The offset of the location's nominal timezone from UTC, in hours.
+    fn offset(&self) -> i64 {
+        (self.longitude / 15.0).round() as i64
+    }
+
+    /// The name of the location's nominal timezone. The signs of the `Etc`
+    /// timezones are inverted, so that `Etc/GMT+8` is eight hours behind UTC.
+    fn timezone(&self) -> String {
+        match self.offset() {
+            0 => "Etc/GMT".to_owned(),
+            offset if offset < 0 => format!("Etc/GMT+{}", -offset),
+            offset => format!("Etc/GMT-{}", offset),
+        }
+    }
+
+    /// The local midnight at the start of the day of the given time.
+    fn midnight(&self, time: u64) -> u64 {
+        let offset = self.offset() * HOUR as i64;
+        let local = time as i64 + offset;
+
+        (local - local.rem_euclid(DAY as i64) - offset).max(0) as u64
+    }
+
+    /// A random number within `[0, 1)` for a channel of the weather at the
+    /// location during a slot of time.
+    fn noise(&self, channel: u64, slot: u64) -> f64 {
+        let latitude = (self.latitude * 1e4).round() as i64 as u64;
+        let longitude = (self.longitude * 1e4).round() as i64 as u64;
+
+        let mut z = self.seed
+            ^ channel.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ slot.wrapping_mul(0xBF58_476D_1CE4_E5B9)
+            ^ latitude.rotate_left(21)
+            ^ longitude.rotate_left(42);
+
+        // SplitMix64.
+        z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Noise which changes smoothly over periods of the given length.
+    fn smooth(&self, channel: u64, time: u64, length: u64) -> f64 {
+        let slot = time / length;
+        let fraction = (time % length) as f64 / length as f64;
+        let eased = fraction * fraction * (3.0 - 2.0 * fraction);
+
+        let (from, to) = (self.noise(channel, slot), self.noise(channel, slot + 1));
+
+        from + (to - from) * eased
+    }
+
+    /// How wet the passing weather system is, within `[0, 1]`.
+    fn wetness(&self, time: u64) -> f64 {
+        self.smooth(1, time, SYSTEM_LENGTH)
+    }
+
+    /// The cloud cover, within `[0, 1]`, following the wetness.
+    fn cloud_cover(&self, time: u64) -> f64 {
+        0.6 * self.wetness(time) + 0.4 * self.smooth(2, time, SYSTEM_LENGTH / 2)
+    }
+
+    /// The local solar time in hours, within `[0, 24)`.
+    fn solar_hour(&self, time: u64) -> f64 {
+        ((time % DAY) as f64 / HOUR as f64 + self.longitude / 15.0).rem_euclid(24.0)
+    }
+
+    /// The elevation of the sun above the horizon, in radians.
+    fn solar_elevation(&self, time: u64) -> f64 {
+        let latitude = self.latitude.to_radians();
+        let declination = declination(time);
+        let hour_angle = ((self.solar_hour(time) - 12.0) * 15.0).to_radians();
+
+        (latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * hour_angle.cos()).asin()
+    }
+
+    /// The sunrise and sunset of the day starting at the given local
+    /// midnight, or `None` during polar day or night.
+    fn sun_times(&self, midnight: u64) -> Option<(u64, u64)> {
+        let utc_midnight = midnight as f64 + self.offset() as f64 * HOUR as f64;
+        let noon = utc_midnight + 43_200.0 - self.longitude * 240.0;
+
+        let latitude = self.latitude.to_radians();
+        let declination = declination(noon.max(0.0) as u64);
+        let cos_hour_angle = ((-0.833f64).to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+
+        if !(-1.0..=1.0).contains(&cos_hour_angle) {
+            return None;
+        }
+
+        let half_day = cos_hour_angle.acos().to_degrees() * 240.0;
+
+        Some(((noon - half_day).max(0.0) as u64, (noon + half_day).max(0.0) as u64))
+    }
+
+    /// The weather at an instant.
+    fn datapoint(&self, time: u64) -> Datapoint {
+        let hour = time / HOUR;
+        let wetness = self.wetness(time);
+        let cloud_cover = self.cloud_cover(time);
+        let daytime = self.solar_elevation(time) > 0.0;
+
+        let season = (2.0 * PI * (day_of_year(time) - 172.0) / 365.25).cos() * self.latitude.signum();
+        let diurnal = 9.0 * (1.0 - 0.5 * cloud_cover) * (2.0 * PI * (self.solar_hour(time) - 15.0) / 24.0).cos();
+        let temperature = 80.0 - 0.8 * self.latitude.abs()
+            + 0.4 * self.latitude.abs() * season
+            + diurnal
+            + (0.5 - wetness) * 8.0
+            + self.noise(3, hour) - 0.5;
+
+        let dew_point = temperature - 2.0 - 18.0 * (1.0 - wetness);
+        let precip_probability = ((wetness - 0.55) / 0.3).clamp(0.0, 1.0);
+        let precip_intensity = if precip_probability >= 0.3 {
+            precip_probability * 0.12 * (0.5 + self.noise(4, hour))
+        } else {
+            0.0
+        };
+        let precip_type = match temperature {
+            _ if precip_intensity == 0.0 => None,
+            temperature if temperature <= 32.0 => Some(PrecipitationType::Snow),
+            temperature if temperature <= 35.0 => Some(PrecipitationType::Sleet),
+            _ => Some(PrecipitationType::Rain),
+        };
+
+        let humidity = relative_humidity(temperature, dew_point);
+        let wind_speed = 2.0 + 14.0 * self.smooth(5, time, SYSTEM_LENGTH);
+        let uv_index = if daytime {
+            (12.0 * self.solar_elevation(time).sin() * (1.0 - 0.7 * cloud_cover)).round()
+        } else {
+            0.0
+        };
+
+        let icon = match precip_type {
+            Some(PrecipitationType::Rain) if precip_probability >= 0.5 => Icon::Rain,
+            Some(PrecipitationType::Sleet) if precip_probability >= 0.5 => Icon::Sleet,
+            Some(PrecipitationType::Snow) if precip_probability >= 0.5 => Icon::Snow,
+            _ if humidity >= 0.97 => Icon::Fog,
+            _ if cloud_cover >= 0.8 => Icon::Cloudy,
+            _ if cloud_cover >= 0.4 && daytime => Icon::PartlyCloudyDay,
+            _ if cloud_cover >= 0.4 => Icon::PartlyCloudyNight,
+            _ if daytime => Icon::ClearDay,
+            _ => Icon::ClearNight,
+        };
+
+        Datapoint {
+            cloud_cover: Some(round(cloud_cover, 2)),
+            dew_point: Some(round(dew_point, 2)),
+            humidity: Some(round(humidity, 2)),
+            icon: Some(icon),
+            ozone: Some(round(280.0 + 60.0 * self.smooth(8, time, DAY), 1)),
+            precip_intensity: Some(round(precip_intensity, 4)),
+            precip_probability: Some(round(precip_probability, 2)),
+            precip_type,
+            pressure: Some(round(1013.25 + (0.5 - wetness) * 30.0, 1)),
+            summary: Some(icon.description().to_owned()),
+            temperature: Some(round(temperature, 2)),
+            time,
+            uv_index: Some(uv_index),
+            visibility: Some(round(10.0 - 8.0 * precip_probability, 2)),
+            wind_bearing: Some((360.0 * self.smooth(7, time, 2 * SYSTEM_LENGTH)).round() % 360.0),
+            wind_gust: Some(round(wind_speed * (1.3 + 0.4 * self.noise(6, hour)), 2)),
+            wind_speed: Some(round(wind_speed, 2)),
+            ..Datapoint::default()
+        }
+    }
+
+    /// The precipitation of each minute, starting at the given time.
+    fn minutes(&self, start: u64, count: u64) -> Vec<Datapoint> {
+        (0..count).map(|minute| {
+            let datapoint = self.datapoint(start + minute * 60);
+
+            Datapoint {
+                precip_intensity: datapoint.precip_intensity,
+                precip_probability: datapoint.precip_probability,
+                precip_type: datapoint.precip_type,
+                time: datapoint.time,
+                ..Datapoint::default()
+            }
+        }).collect()
+    }
+
+    /// The weather of each hour, starting at the given time.
+    fn hours(&self, start: u64, count: u64) -> Vec<Datapoint> {
+        (0..count).map(|hour| self.datapoint(start + hour * HOUR)).collect()
+    }
+
+    /// The weather of each day, starting at the given local midnight.
+    fn days(&self, midnight: u64, count: u64) -> Vec<Datapoint> {
+        (0..count).map(|day| self.day(midnight + day * DAY)).collect()
+    }
+
+    /// The weather of the day starting at the given local midnight, summarized
+    /// from its hours, with measurements which are not extremes taken from
+    /// the early afternoon.
+    fn day(&self, midnight: u64) -> Datapoint {
+        fn extreme<F>(hours: &[Datapoint], value: F, highest: bool) -> (f64, u64)
+            where F: Fn(&Datapoint) -> Option<f64> {
+            let mut extreme = (value(&hours[0]).unwrap_or(0.0), hours[0].time);
+
+            for datapoint in hours {
+                let current = value(datapoint).unwrap_or(0.0);
+
+                if (highest && current > extreme.0) || (!highest && current < extreme.0) {
+                    extreme = (current, datapoint.time);
+                }
+            }
+
+            extreme
+        }
+
+        let hours = self.hours(midnight, 24);
+        let afternoon = &hours[14];
+
+        let (temperature_max, temperature_max_time) = extreme(&hours, |datapoint| datapoint.temperature, true);
+        let (temperature_min, temperature_min_time) = extreme(&hours, |datapoint| datapoint.temperature, false);
+        let (precip_intensity_max, precip_intensity_max_time) = extreme(&hours, |datapoint| datapoint.precip_intensity, true);
+        let (precip_probability, wettest) = extreme(&hours, |datapoint| datapoint.precip_probability, true);
+        let (wind_gust, wind_gust_time) = extreme(&hours, |datapoint| datapoint.wind_gust, true);
+        let (uv_index, uv_index_time) = extreme(&hours, |datapoint| datapoint.uv_index, true);
+
+        let wettest = &hours[((wettest - midnight) / HOUR) as usize];
+        let precip_intensity = hours.iter()
+            .filter_map(|datapoint| datapoint.precip_intensity)
+            .sum::<f64>() / hours.len() as f64;
+
+        let icon = match (wettest.icon, afternoon.icon) {
+            (Some(icon @ Icon::Rain), _) | (Some(icon @ Icon::Sleet), _) | (Some(icon @ Icon::Snow), _) => icon,
+            (_, Some(Icon::ClearNight)) => Icon::ClearDay,
+            (_, Some(Icon::PartlyCloudyNight)) => Icon::PartlyCloudyDay,
+            (_, Some(icon)) => icon,
+            (_, None) => Icon::ClearDay,
+        };
+        let sun_times = self.sun_times(midnight);
+        let moon_phase = ((midnight as f64 - NEW_MOON) / DAY as f64 / LUNATION_DAYS).rem_euclid(1.0);
+
+        Datapoint {
+            cloud_cover: afternoon.cloud_cover,
+            dew_point: afternoon.dew_point,
+            humidity: afternoon.humidity,
+            icon: Some(icon),
+            moon_phase: Some(round(moon_phase, 2)),
+            ozone: afternoon.ozone,
+            precip_intensity: Some(round(precip_intensity, 4)),
+            precip_intensity_max: Some(precip_intensity_max),
+            precip_intensity_max_time: Some(precip_intensity_max_time),
+            precip_probability: Some(precip_probability),
+            precip_type: wettest.precip_type,
+            pressure: afternoon.pressure,
+            summary: Some(format!("{} throughout the day.", icon.description())),
+            sunrise_time: sun_times.map(|(sunrise, _)| sunrise),
+            sunset_time: sun_times.map(|(_, sunset)| sunset),
+            temperature_max: Some(temperature_max),
+            temperature_max_time: Some(temperature_max_time),
+            temperature_min: Some(temperature_min),
+            temperature_min_time: Some(temperature_min_time),
+            time: midnight,
+            uv_index: Some(uv_index),
+            uv_index_time: Some(uv_index_time),
+            visibility: afternoon.visibility,
+            wind_bearing: afternoon.wind_bearing,
+            wind_gust: Some(wind_gust),
+            wind_gust_time: Some(wind_gust_time),
+            wind_speed: afternoon.wind_speed,
+            ..Datapoint::default()
+        }
+    }
+}
+
+/// Creates a datablock summarized by the most common icon of its datapoints.
+fn datablock(data: Vec<Datapoint>, period: &str) -> Datablock {
+    let mut counts: Vec<(Icon, usize)> = vec![];
+
+    for icon in data.iter().filter_map(|datapoint| datapoint.icon) {
+        match counts.iter_mut().find(|&&mut (counted, _)| counted == icon) {
+            Some(&mut (_, ref mut count)) => *count += 1,
+            None => counts.push((icon, 1)),
+        }
+    }
+
+    let icon = counts.iter()
+        .fold(None, |most: Option<(Icon, usize)>, &(icon, count)| match most {
+            Some((_, most_count)) if most_count >= count => most,
+            _ => Some((icon, count)),
+        })
+        .map(|(icon, _)| icon);
+
+    Datablock {
+        data: Some(data),
+        icon,
+        summary: icon.map(|icon| format!("{} {}.", icon.description(), period)),
+    }
+}
+
+/// The day of the year of the time, starting from `0`, approximately.
+fn day_of_year(time: u64) -> f64 {
+    (time / DAY) as f64 % 365.2422
+}
+
+/// The declination of the sun at the time, in radians.
+fn declination(time: u64) -> f64 {
+    23.44f64.to_radians() * (2.0 * PI * (day_of_year(time) + 285.0) / 365.0).sin()
+}
+
+/// The relative humidity, within `[0, 1]`, of air at a temperature and dew
+/// point in degrees Fahrenheit, by the Magnus formula.
+fn relative_humidity(temperature: f64, dew_point: f64) -> f64 {
+    let magnus = |fahrenheit: f64| {
+        let celsius = (fahrenheit - 32.0) * 5.0 / 9.0;
+
+        17.625 * celsius / (243.04 + celsius)
+    };
+
+    (magnus(dew_point) - magnus(temperature)).exp().min(1.0)
+}
+
+/// Rounds the value to the given number of decimal places.
+fn round(value: f64, places: i32) -> f64 {
+    let scale = 10f64.powi(places);
+
+    (value * scale).round() / scale
+}
@@ -0,0 +1,383 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! A minimal HTTP transport seam, for requesting forecasts through any HTTP
+//! client or serving canned responses in tests.
+
+use de;
+use models::{Forecast, ForecastResponse};
+use std::time::Duration;
+use {DarkskyRequester, Error, Options, Result};
+use {build_time_machine_uri, build_uri};
+
+#[cfg(feature="ureq")]
+use error::from_transport_io;
+#[cfg(feature="ureq")]
+use std::io::Read;
+#[cfg(feature="ureq")]
+use ureq::{Agent, AgentBuilder, Error as UreqError, Request};
+
+/// A response received by an [`HttpTransport`].
+///
+/// [`HttpTransport`]: trait.HttpTransport.html
+#[derive(Clone, Debug, Default)]
+pub struct TransportResponse {
+    /// The body of the response.
+    pub body: Vec<u8>,
+    /// The headers of the response, as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The status code of the response, such as `200`.
+    pub status: u16,
+}
+
+impl TransportResponse {
+    /// Creates a successful response with the given body and no headers.
+    pub fn ok<B: Into<Vec<u8>>>(body: B) -> TransportResponse {
+        TransportResponse {
+            body: body.into(),
+            headers: vec![],
+            status: 200,
+        }
+    }
+
+    /// The value of the first header with the given name, compared
+    /// case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| &value[..])
+    }
+
+    /// Checks the status code and content type of the response, and parses
+    /// its body into a forecast.
+    fn forecast(&self) -> Result<Forecast> {
+        let body = de::decode_body(self.header(de::ENCODING_HEADER), &self.body)?;
+        de::check_status(self.status, &body)?;
+        de::check_content_type(self.header("Content-Type"))?;
+
+        de::forecast_from_response(self.status, &body)
+    }
+}
+
+/// A way of sending `GET` requests, for which [`DarkskyRequester`] is
+/// implemented.
+///
+/// The requester builds each request's URI, such as with [`build_uri`], and
+/// checks and parses the response, so a transport only needs to fetch the
+/// URI. This allows forecasts to be requested through any HTTP client, and
+/// canned responses to be served in tests, exercising the full path from
+/// building the URI to parsing the forecast without a token or network.
+///
+/// The response is buffered in full, so the progress callback of
+/// [`get_forecast_with_progress`] is called once, with the length of the body.
+///
+/// # Examples
+///
+/// ```rust
+/// use darksky::{DarkskyRequester, HttpTransport, Result, TransportResponse};
+///
+/// struct Canned(&'static str);
+///
+/// impl HttpTransport for Canned {
+///     fn get(&self, _uri: &str) -> Result<TransportResponse> {
+///         Ok(TransportResponse::ok(self.0))
+///     }
+/// }
+///
+/// let transport = Canned(r#"{"latitude": 37.8267, "longitude": -122.423, "timezone": "America/Los_Angeles"}"#);
+/// let forecast = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+///
+/// assert_eq!(forecast.timezone, "America/Los_Angeles");
+/// ```
+///
+/// [`DarkskyRequester`]: trait.DarkskyRequester.html
+/// [`build_uri`]: fn.build_uri.html
+/// [`get_forecast_with_progress`]: trait.DarkskyRequester.html#method.get_forecast_with_progress
+pub trait HttpTransport {
+    /// Sends a `GET` request to the URI, returning the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent or its response
+    /// could not be read. Unsuccessful status codes should be returned as a
+    /// response rather than as an error, so that they are reported as an
+    /// [`Error::Api`].
+    ///
+    /// [`Error::Api`]: enum.Error.html#variant.Api
+    fn get(&self, uri: &str) -> Result<TransportResponse>;
+
+    /// Sends a `GET` request to the URI like [`get`], failing with an
+    /// [`Error::Timeout`] containing the timeout if the request, from
+    /// connecting through reading the whole response, does not complete
+    /// within it.
+    ///
+    /// This is used by [`DarkskyRequester::get_forecast_with_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get`], as well as an [`Error::Timeout`].
+    ///
+    /// The default implementation can not bound the request, so it returns an
+    /// [`Error::InvalidOptions`] without sending it.
+    ///
+    /// [`DarkskyRequester::get_forecast_with_timeout`]: trait.DarkskyRequester.html#method.get_forecast_with_timeout
+    /// [`Error::InvalidOptions`]: enum.Error.html#variant.InvalidOptions
+    /// [`Error::Timeout`]: enum.Error.html#variant.Timeout
+    /// [`get`]: #tymethod.get
+    fn get_with_timeout(&self, _uri: &str, _timeout: Duration) -> Result<TransportResponse> {
+        Err(Error::InvalidOptions("timeouts are not supported by this transport"))
+    }
+}
+
+impl<T: HttpTransport> DarkskyRequester for T {
+    fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast> {
+        self.get_forecast_with_opts(token, latitude, longitude, Options::default())
+    }
+
+    fn get_forecast_with_options<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+        self.get_forecast_with_opts(token, latitude, longitude, options(Options::default()))
+    }
+
+    fn get_forecast_with_opts(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: Options
+    ) -> Result<Forecast> {
+        let uri = build_uri(token, latitude, longitude, &options)?;
+
+        self.get(&uri)?.forecast()
+    }
+
+    fn get_forecast_with_timeout(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        timeout: Duration
+    ) -> Result<Forecast> {
+        let uri = build_uri(token, latitude, longitude, &Options::default())?;
+
+        self.get_with_timeout(&uri, timeout)?.forecast()
+    }
+
+    fn get_forecast_with_progress<F, P>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F,
+        mut progress: P
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options, P: FnMut(u64) {
+        let options = options(Options::default());
+        let uri = build_uri(token, latitude, longitude, &options)?;
+
+        let response = self.get(&uri)?;
+        progress(response.body.len() as u64);
+
+        response.forecast()
+    }
+
+    fn get_forecast_with_meta<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        options: F
+    ) -> Result<ForecastResponse> where F: FnOnce(Options) -> Options {
+        let options = options(Options::default());
+        let uri = build_uri(token, latitude, longitude, &options)?;
+
+        let response = self.get(&uri)?;
+
+        Ok(ForecastResponse {
+            api_calls: response.header(de::API_CALLS_HEADER).and_then(de::api_calls),
+            cache_hit: response.header(de::CACHE_HEADER).and_then(de::cache_hit),
+            forecast: response.forecast()?,
+            response_time_ms: response.header(de::RESPONSE_TIME_HEADER).and_then(de::response_time_ms),
+        })
+    }
+
+    fn get_time_machine_with_options<F>(
+        &self,
+        token: &str,
+        latitude: f64,
+        longitude: f64,
+        time: u64,
+        options: F
+    ) -> Result<Forecast> where F: FnOnce(Options) -> Options {
+        let options = options(Options::default());
+        let uri = build_time_machine_uri(token, latitude, longitude, time, &options)?;
+
+        self.get(&uri)?.forecast()
+    }
+}
+
+/// A blocking [`HttpTransport`] over a `ureq` [`Agent`], with TLS handled by
+/// rustls.
+///
+/// This requires the `ureq` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # #[cfg(feature="ureq")]
+/// # fn try_main() -> Result<(), Box<dyn std::error::Error>> {
+/// use darksky::{DarkskyRequester, UreqTransport};
+/// use std::env;
+///
+/// let token = env::var("FORECAST_TOKEN")?;
+/// let forecast = UreqTransport::new().get_forecast(&token, 37.8267, -122.423)?;
+///
+/// println!("{:?}", forecast.currently);
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`Agent`]: https://docs.rs/ureq/2/ureq/struct.Agent.html
+/// [`HttpTransport`]: trait.HttpTransport.html
+#[cfg(feature="ureq")]
+#[derive(Clone, Debug)]
+pub struct UreqTransport {
+    agent: Agent,
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature="ureq")]
+impl UreqTransport {
+    /// Creates a transport over an agent with the default configuration.
+    pub fn new() -> Self {
+        UreqTransport {
+            agent: Agent::new(),
+            timeout: None,
+        }
+    }
+
+    /// Creates a transport over an agent which limits each request, from
+    /// connecting through reading the whole response, to the given timeout.
+    ///
+    /// A request exceeding it fails with an [`Error::Timeout`] containing the
+    /// timeout, and its connection is closed, so a stalled server can not
+    /// hold up the caller for longer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature="ureq")]
+    /// # fn try_main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use darksky::{DarkskyRequester, Error, UreqTransport};
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// let token = env::var("FORECAST_TOKEN")?;
+    /// let transport = UreqTransport::with_timeout(Duration::from_secs(5));
+    ///
+    /// match transport.get_forecast(&token, 37.8267, -122.423) {
+    ///     Ok(forecast) => println!("{:?}", forecast.currently),
+    ///     Err(Error::Timeout(_)) => println!("The API took too long"),
+    ///     Err(why) => return Err(why.into()),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`Error::Timeout`]: enum.Error.html#variant.Timeout
+    pub fn with_timeout(timeout: Duration) -> Self {
+        UreqTransport {
+            agent: AgentBuilder::new().timeout(timeout).build(),
+            timeout: Some(timeout),
+        }
+    }
+
+    /// Sends the request and reads its response, reporting a timed out
+    /// request with the timeout it was sent with.
+    fn fetch(request: Request, timeout: Option<Duration>) -> Result<TransportResponse> {
+        Self::read(request).map_err(|why| match why {
+            Error::Timeout(None) => Error::Timeout(timeout),
+            why => why,
+        })
+    }
+
+    /// Sends the request and reads its response.
+    fn read(request: Request) -> Result<TransportResponse> {
+        let response = match request.call() {
+            Ok(response) | Err(UreqError::Status(_, response)) => response,
+            Err(why) => return Err(why.into()),
+        };
+
+        let status = response.status();
+        let headers = response.headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = response.header(&name)?.to_owned();
+
+                Some((name, value))
+            })
+            .collect();
+
+        let mut body = vec![];
+        response.into_reader().read_to_end(&mut body).map_err(from_transport_io)?;
+
+        Ok(TransportResponse {
+            body,
+            headers,
+            status,
+        })
+    }
+}
+
+#[cfg(feature="ureq")]
+impl Default for UreqTransport {
+    fn default() -> Self {
+        UreqTransport::new()
+    }
+}
+
+#[cfg(feature="ureq")]
+impl From<Agent> for UreqTransport {
+    fn from(agent: Agent) -> Self {
+        UreqTransport {
+            agent,
+            timeout: None,
+        }
+    }
+}
+
+#[cfg(feature="ureq")]
+impl HttpTransport for UreqTransport {
+    fn get(&self, uri: &str) -> Result<TransportResponse> {
+        Self::fetch(self.agent.get(uri), self.timeout)
+    }
+
+    /// Sends the request like [`get`], overriding the timeout of the agent,
+    /// if any, for this request.
+    ///
+    /// [`get`]: #method.get
+    fn get_with_timeout(&self, uri: &str, timeout: Duration) -> Result<TransportResponse> {
+        Self::fetch(self.agent.get(uri).timeout(timeout), Some(timeout))
+    }
+}
@@ -0,0 +1,163 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zey@zey.moe>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Rising and falling trends of measurements over the coming hours.
+
+use models::{Datapoint, Forecast};
+
+/// The direction a measurement is heading in, as part of a set of [`Trends`].
+///
+/// [`Trends`]: struct.Trends.html
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Trend {
+    /// The measurement is rising.
+    Rising,
+    /// The measurement is neither meaningfully rising nor falling.
+    Steady,
+    /// The measurement is falling.
+    Falling,
+}
+
+impl Trend {
+    /// An arrow depicting the trend: `"↑"`, `"→"`, or `"↓"`.
+    pub fn arrow(&self) -> &'static str {
+        match *self {
+            Trend::Rising => "↑",
+            Trend::Steady => "→",
+            Trend::Falling => "↓",
+        }
+    }
+}
+
+/// The trends of several measurements over the coming hours, as returned by
+/// [`Forecast::trends`].
+///
+/// Each is `None` if there is not enough data to determine a trend.
+///
+/// [`Forecast::trends`]: struct.Forecast.html#method.trends
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Trends {
+    /// The trend of the humidity, steady within 5 percentage points.
+    pub humidity: Option<Trend>,
+    /// The trend of the pressure, steady within 1 millibar or hectopascal.
+    pub pressure: Option<Trend>,
+    /// The trend of the temperature, steady within 1 degree.
+    pub temperature: Option<Trend>,
+    /// The trend of the wind speed, steady within 1 unit of speed.
+    pub wind: Option<Trend>,
+}
+
+impl Forecast {
+    /// The default number of hours ahead considered by [`trends`].
+    ///
+    /// [`trends`]: #method.trends
+    pub const DEFAULT_TREND_HOURS: usize = 3;
+
+    /// The trends of the temperature, pressure, humidity, and wind speed over
+    /// the [default number of hours][`DEFAULT_TREND_HOURS`] ahead.
+    ///
+    /// Refer to [`trends_within`] for how trends are determined.
+    ///
+    /// [`DEFAULT_TREND_HOURS`]: #associatedconstant.DEFAULT_TREND_HOURS
+    /// [`trends_within`]: #method.trends_within
+    pub fn trends(&self) -> Trends {
+        self.trends_within(Self::DEFAULT_TREND_HOURS)
+    }
+
+    /// The trends of the temperature, pressure, humidity, and wind speed over
+    /// the given number of hours ahead, for display as glanceable indicators.
+    ///
+    /// Each trend is taken from the slope of a line fitted to the
+    /// [`currently`] value and the values of the next hours in the
+    /// [hourly block][`hourly`], or to the first hours of the hourly block if
+    /// the current datapoint is absent. A measurement which is expected to
+    /// change by less than its tolerance over the window, documented on each
+    /// field of [`Trends`], is [`Trend::Steady`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use darksky::Forecast;
+    ///
+    /// fn widget(forecast: &Forecast) -> String {
+    ///     match forecast.trends().temperature {
+    ///         Some(trend) => format!("Temperature {}", trend.arrow()),
+    ///         None => "Temperature".to_owned(),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Trend::Steady`]: enum.Trend.html#variant.Steady
+    /// [`Trends`]: struct.Trends.html
+    /// [`currently`]: #structfield.currently
+    /// [`hourly`]: #structfield.hourly
+    pub fn trends_within(&self, hours: usize) -> Trends {
+        let hourly = self.hourly_points();
+
+        let window = match self.currently {
+            Some(ref currently) => {
+                let ahead = hourly.iter()
+                    .filter(|datapoint| datapoint.time > currently.time)
+                    .take(hours);
+
+                Some(currently).into_iter().chain(ahead).collect::<Vec<_>>()
+            },
+            None => hourly.iter().take(hours + 1).collect(),
+        };
+
+        Trends {
+            humidity: trend(&window, |datapoint| datapoint.humidity, hours, 0.05),
+            pressure: trend(&window, |datapoint| datapoint.pressure, hours, 1.0),
+            temperature: trend(&window, |datapoint| datapoint.temperature, hours, 1.0),
+            wind: trend(&window, |datapoint| datapoint.wind_speed, hours, 1.0),
+        }
+    }
+}
+
+/// Fits a line to the values present in the window by least squares, and
+/// compares its change over the given number of hours to the tolerance.
+fn trend<F>(window: &[&Datapoint], value: F, hours: usize, tolerance: f64) -> Option<Trend>
+    where F: Fn(&Datapoint) -> Option<f64> {
+    let points = window.iter()
+        .filter_map(|datapoint| value(datapoint).map(|value| (datapoint.time as f64 / 3600.0, value)))
+        .collect::<Vec<_>>();
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|&(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|&(_, y)| y).sum::<f64>() / count;
+
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(covariance, variance), &(x, y)| {
+        (covariance + (x - mean_x) * (y - mean_y), variance + (x - mean_x).powi(2))
+    });
+
+    if variance == 0.0 {
+        return None;
+    }
+
+    let change = covariance / variance * hours as f64;
+
+    Some(if change >= tolerance {
+        Trend::Rising
+    } else if change <= -tolerance {
+        Trend::Falling
+    } else {
+        Trend::Steady
+    })
+}
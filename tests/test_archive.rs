@@ -0,0 +1,39 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+use std::env;
+use std::fs::{self, File};
+use std::io;
+
+/// A transport serving the fixture forecast in place of the API.
+struct FixtureTransport;
+
+impl HttpTransport for FixtureTransport {
+	fn get(&self, _: &str) -> Result<TransportResponse> {
+		Ok(TransportResponse::ok(include_str!("fixtures/forecast.json")))
+	}
+}
+
+#[test]
+fn test_archive_forecast() {
+	let dir = env::temp_dir().join("darksky-test-archive");
+	let _ = fs::remove_dir_all(&dir);
+
+	let path = FixtureTransport.archive_forecast("token", 37.8267, -122.4233, &dir).unwrap();
+
+	assert_eq!(path.parent(), Some(dir.join("37.8267_-122.4233").as_path()));
+	assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some("json"));
+
+	let archived: Forecast = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+	let expected = FixtureTransport.get_forecast("token", 37.8267, -122.4233).unwrap();
+	assert!(archived.content_eq(&expected));
+
+	match FixtureTransport.archive_forecast("token", 37.8267, -122.4233, &dir) {
+		Ok(second) => assert_ne!(second, path),
+		Err(Error::Io(ref why)) => assert_eq!(why.kind(), io::ErrorKind::AlreadyExists),
+		Err(why) => panic!("Expected an io error, got {:?}", why),
+	}
+
+	fs::remove_dir_all(&dir).unwrap();
+}
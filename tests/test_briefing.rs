@@ -0,0 +1,43 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+fn fixture() -> Forecast {
+	serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap()
+}
+
+#[test]
+fn test_briefing_rain() {
+	let forecast = fixture();
+	let briefing = Briefing::from(&forecast).with_rain().build();
+
+	assert_eq!(briefing.next_rain, Some(TimeWindow { start: 1510002000, end: 1510012800 }));
+	assert!(briefing.alerts.is_empty());
+	assert_eq!(briefing.high, None);
+
+	let briefing = Briefing::from(&forecast).with_rain_threshold(0.2).build();
+	assert_eq!(briefing.next_rain, Some(TimeWindow { start: 1509998400, end: 1510016400 }));
+
+	let briefing = Briefing::from(&forecast).with_rain_threshold(0.9).build();
+	assert_eq!(briefing.next_rain, None);
+}
+
+#[test]
+fn test_briefing_alerts() {
+	let forecast = fixture();
+	let briefing = Briefing::from(&forecast).with_alerts().build();
+
+	assert_eq!(briefing.alerts.len(), 1);
+	assert_eq!(briefing.alerts[0].title, "Flood Watch for Mason, WA");
+	assert_eq!(briefing.next_rain, None);
+}
+
+#[test]
+fn test_briefing_temperature() {
+	let forecast = fixture();
+	let briefing = Briefing::from(&forecast).with_temperature().build();
+
+	assert_eq!(briefing.high, Some(61.2));
+	assert_eq!(briefing.low, Some(50.4));
+}
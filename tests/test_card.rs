@@ -0,0 +1,39 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+#[test]
+fn test_render_card() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let expected = "\
++------------------------------------------+
+| 37.8267, -122.4233 (America/Los_Angeles) |
+|                                          |
+|              Overcast                    |
+|     .--.     58.3 °F, feels like 57.9 °F |
+|  .-(    ).   Wind 6.1 mph S              |
+| (___.__)__)  High 61.2 °F / Low 50.4 °F  |
+|                                          |
+| Rain likely in about 3 hours             |
++------------------------------------------+";
+
+	assert_eq!(forecast.render_card(Unit::Us), expected);
+}
+
+#[test]
+fn test_render_card_missing_fields() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	let expected = "\
++-----------------------------------------+
+| 37.8267, -122.423 (America/Los_Angeles) |
++-----------------------------------------+";
+
+	assert_eq!(forecast.render_card(Unit::Auto), expected);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature="chrono")]
+
+extern crate chrono;
+extern crate darksky;
+extern crate serde_json;
+
+use chrono::{TimeZone, Utc};
+use darksky::*;
+
+#[test]
+fn test_datapoint_utc() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"sunriseTime": 1509978000,
+		"sunsetTime": 1510015200
+	}"#).unwrap();
+
+	assert_eq!(datapoint.time_utc(), Utc.timestamp_opt(1509993277, 0).single());
+	assert_eq!(datapoint.sunrise_utc(), Utc.timestamp_opt(1509978000, 0).single());
+	assert_eq!(datapoint.sunset_utc(), Utc.timestamp_opt(1510015200, 0).single());
+
+	let datapoint: Datapoint = serde_json::from_str(r#"{"time": 18446744073709551615}"#).unwrap();
+	assert_eq!(datapoint.time_utc(), None);
+	assert_eq!(datapoint.sunrise_utc(), None);
+}
+
+#[test]
+fn test_alert_expires_utc() {
+	let alert: Alert = serde_json::from_str(r#"{
+		"description": "",
+		"expires": 1510015200,
+		"title": "",
+		"uri": ""
+	}"#).unwrap();
+	assert_eq!(alert.expires_utc(), Utc.timestamp_opt(1510015200, 0).single());
+
+	let alert: Alert = serde_json::from_str(r#"{"description": "", "title": "", "uri": ""}"#).unwrap();
+	assert_eq!(alert.expires_utc(), None);
+}
+
+#[test]
+fn test_unix_to_utc() {
+	assert_eq!(unix_to_utc(0), Some(Utc.timestamp_opt(0, 0).unwrap()));
+	assert_eq!(unix_to_utc(u64::MAX), None);
+}
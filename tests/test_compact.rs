@@ -0,0 +1,20 @@
+#![cfg(feature="compact")]
+
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+#[test]
+fn test_compact_skips_errors() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"temperature": 58.3,
+		"temperatureError": 1.2,
+		"windSpeedError": 0.4
+	}"#).unwrap();
+
+	assert_eq!(datapoint.temperature, Some(58.3));
+	assert_eq!(datapoint.temperature_error, None);
+	assert_eq!(datapoint.wind_speed_error, None);
+}
@@ -0,0 +1,86 @@
+extern crate darksky;
+extern crate serde_json;
+
+#[cfg(feature="hyper")]
+extern crate hyper;
+
+use darksky::*;
+use std::io::{self, ErrorKind as IoErrorKind, Read};
+use std::time::Duration;
+
+/// A reader which always fails with the given kind of IO error.
+struct FailingReader(IoErrorKind);
+
+impl Read for FailingReader {
+	fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+		Err(io::Error::new(self.0, "failed"))
+	}
+}
+
+#[test]
+fn test_error_kind() {
+	let decode = Error::Decode("expected a number", serde_json::Value::Null);
+	assert_eq!(decode.kind(), ErrorKind::Decode);
+
+	let decode_path = Error::DecodePath {
+		path: "hourly.data[3].temperature".to_owned(),
+		message: "invalid type".to_owned(),
+	};
+	assert_eq!(decode_path.kind(), ErrorKind::Decode);
+
+	let json = serde_json::from_str::<Forecast>("{}").unwrap_err();
+	assert_eq!(Error::from(json).kind(), ErrorKind::Decode);
+
+	let read = serde_json::from_reader::<_, Forecast>(FailingReader(IoErrorKind::ConnectionReset)).unwrap_err();
+	assert_eq!(Error::from(read).kind(), ErrorKind::Network);
+
+	let io = io::Error::new(IoErrorKind::PermissionDenied, "denied");
+	assert_eq!(Error::from(io).kind(), ErrorKind::Io);
+
+	let timeout = io::Error::new(IoErrorKind::TimedOut, "timed out");
+	assert_eq!(Error::from(timeout).kind(), ErrorKind::Timeout);
+
+	assert_eq!(Error::MalformedToken.kind(), ErrorKind::Auth);
+	assert_eq!(Error::MissingToken.kind(), ErrorKind::Auth);
+
+	let api = |code, message: &str| Error::Api { code, message: message.to_owned() };
+	assert_eq!(api(403, "permission denied").kind(), ErrorKind::Auth);
+	assert_eq!(api(403, "daily usage limit exceeded").kind(), ErrorKind::Quota);
+	assert_eq!(api(429, "too many requests").kind(), ErrorKind::Quota);
+	assert_eq!(api(400, "The given location is invalid.").kind(), ErrorKind::Other);
+	assert_eq!(api(400, "The given location is invalid.").to_string(), "API error 400: The given location is invalid.");
+
+	let content_type = Error::UnexpectedContentType { content_type: "text/html".to_owned() };
+	assert_eq!(content_type.kind(), ErrorKind::Network);
+	assert_eq!(Error::StaleData { age: Duration::from_secs(60) }.kind(), ErrorKind::Other);
+}
+
+#[test]
+fn test_error_is_transient() {
+	let api = |code| Error::Api { code, message: "error".to_owned() };
+	assert!(api(500).is_transient());
+	assert!(api(503).is_transient());
+	assert!(!api(400).is_transient());
+	assert!(!api(403).is_transient());
+
+	let read = serde_json::from_reader::<_, Forecast>(FailingReader(IoErrorKind::ConnectionReset)).unwrap_err();
+	assert!(Error::from(read).is_transient());
+
+	let json = serde_json::from_str::<Forecast>("{}").unwrap_err();
+	assert!(!Error::from(json).is_transient());
+	assert!(!Error::MissingToken.is_transient());
+
+	let timeout = Error::Timeout(Some(Duration::from_secs(5)));
+	assert!(timeout.is_transient());
+	assert_eq!(timeout.kind(), ErrorKind::Timeout);
+}
+
+#[cfg(feature="hyper")]
+#[test]
+fn test_error_kind_hyper() {
+	assert_eq!(Error::from(hyper::Error::Status).kind(), ErrorKind::Network);
+
+	let timeout = io::Error::new(IoErrorKind::TimedOut, "timed out");
+	assert_eq!(Error::from(hyper::Error::Io(timeout)).kind(), ErrorKind::Timeout);
+	assert!(Error::from(hyper::Error::Status).is_transient());
+}
@@ -0,0 +1,93 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+#[cfg(feature="extra-fields")]
+use serde_json::Value;
+
+const FORECAST: &str = r#"{
+	"currently": {
+		"time": 1509993277,
+		"temperature": 58.3,
+		"temperatureError": 1.2,
+		"smokeIndex": 3
+	},
+	"latitude": 37.8267,
+	"longitude": -122.423,
+	"timezone": "America/Los_Angeles",
+	"elevation": 12.5
+}"#;
+
+#[cfg(feature="extra-fields")]
+#[test]
+fn test_extra_fields_captured() {
+	let forecast = Forecast::from_json_str(FORECAST).unwrap();
+	let currently = forecast.currently.as_ref().unwrap();
+
+	assert_eq!(forecast.extra.get("elevation"), Some(&Value::from(12.5)));
+	assert_eq!(forecast.extra.len(), 1);
+	assert_eq!(currently.extra.get("smokeIndex"), Some(&Value::from(3)));
+	assert_eq!(currently.temperature, Some(58.3));
+	assert!(!currently.extra.contains_key("temperature"));
+
+	let known = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	assert!(known.extra.is_empty());
+	assert!(known.hourly_points().iter().all(|datapoint| datapoint.extra.is_empty()));
+}
+
+#[cfg(feature="extra-fields")]
+#[test]
+fn test_extra_fields_round_trip() {
+	let forecast = Forecast::from_json_str(FORECAST).unwrap();
+	let json = serde_json::to_value(&forecast).unwrap();
+
+	assert_eq!(json["elevation"], Value::from(12.5));
+	assert_eq!(json["currently"]["smokeIndex"], Value::from(3));
+	assert_eq!(Forecast::from_json_str(&json.to_string()).unwrap(), forecast);
+}
+
+#[cfg(all(feature="compact", feature="extra-fields"))]
+#[test]
+fn test_extra_fields_compact() {
+	let forecast = Forecast::from_json_str(FORECAST).unwrap();
+	let currently = forecast.currently.unwrap();
+
+	assert_eq!(currently.temperature_error, None);
+	assert!(!currently.extra.contains_key("temperatureError"));
+}
+
+#[cfg(not(feature="extra-fields"))]
+#[test]
+fn test_extra_fields_discarded() {
+	let forecast = Forecast::from_json_str(FORECAST).unwrap();
+
+	assert!(forecast.extra.is_empty());
+	assert!(forecast.currently.as_ref().unwrap().extra.is_empty());
+
+	let json = serde_json::to_value(&forecast).unwrap();
+	assert_eq!(json.get("extra"), None);
+	assert_eq!(json.get("elevation"), None);
+}
+
+#[test]
+fn test_extra_fields_literal() {
+	let forecast = Forecast {
+		alerts: vec![],
+		currently: Some(Datapoint {
+			time: 1509993277,
+			..Datapoint::default()
+		}),
+		daily: None,
+		extra: Default::default(),
+		flags: None,
+		hourly: None,
+		latitude: 37.8267,
+		longitude: -122.423,
+		minutely: None,
+		offset: None,
+		timezone: "America/Los_Angeles".to_owned(),
+	};
+
+	assert_eq!(Forecast::from_json_str(&serde_json::to_string(&forecast).unwrap()).unwrap(), forecast);
+}
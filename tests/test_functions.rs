@@ -1,3 +1,5 @@
+#![cfg(feature="hyper")]
+
 extern crate darksky;
 extern crate hyper;
 extern crate hyper_native_tls;
@@ -40,3 +42,42 @@ fn test_get_forecast_with_options() {
 		   .unit(Unit::Si)
 	}).unwrap();
 }
+
+#[ignore]
+#[test]
+fn test_healthcheck() {
+	let token = env::var("FORECAST_TOKEN").expect("forecast token");
+
+	let client = client();
+	client.healthcheck(&token[..]).unwrap();
+	assert!(client.healthcheck("0000").is_err());
+}
+
+#[ignore]
+#[test]
+fn test_get_forecast_with_progress() {
+	let token = env::var("FORECAST_TOKEN").expect("forecast token");
+
+	let client = client();
+	let mut received = vec![];
+	client.get_forecast_with_progress(&token[..], 37.8267, -122.423, |opt| {
+		opt.extend_hourly()
+	}, |bytes| received.push(bytes)).unwrap();
+
+	assert!(!received.is_empty());
+	assert!(received.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[ignore]
+#[test]
+fn test_get_time_machine() {
+	let token = env::var("FORECAST_TOKEN").expect("forecast token");
+
+	let client = client();
+	let forecast = client.get_time_machine(&token[..], 37.8267, -122.423, 1509993277).unwrap();
+	assert_eq!(forecast.currently.map(|currently| currently.time), Some(1509993277));
+
+	assert!(client.get_time_machine_with_options(&token[..], 37.8267, -122.423, 1509993277, |opt| {
+		opt.exclude(vec![Block::Hourly])
+	}).is_ok());
+}
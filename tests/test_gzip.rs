@@ -0,0 +1,49 @@
+#![cfg(feature="gzip")]
+
+extern crate darksky;
+
+use darksky::*;
+
+/// A transport serving a canned response.
+struct Canned(TransportResponse);
+
+impl HttpTransport for Canned {
+	fn get(&self, _uri: &str) -> Result<TransportResponse> {
+		Ok(self.0.clone())
+	}
+}
+
+fn gzipped(body: &[u8]) -> Canned {
+	let mut response = TransportResponse::ok(body);
+	response.headers.push(("Content-Type".to_owned(), "application/json".to_owned()));
+	response.headers.push(("Content-Encoding".to_owned(), "gzip".to_owned()));
+
+	Canned(response)
+}
+
+#[test]
+fn test_gzip_response_decompressed() {
+	let transport = gzipped(include_bytes!("fixtures/forecast.json.gz"));
+	let forecast = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+
+	let expected = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	assert_eq!(forecast, expected);
+}
+
+#[test]
+fn test_uncompressed_response_unchanged() {
+	let transport = Canned(TransportResponse::ok(include_str!("fixtures/forecast.json")));
+	let forecast = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+
+	assert_eq!(forecast.timezone, "America/Los_Angeles");
+}
+
+#[test]
+fn test_invalid_gzip_response() {
+	let transport = gzipped(include_bytes!("fixtures/forecast.json"));
+
+	match transport.get_forecast("token", 37.8267, -122.423) {
+		Err(Error::Io(_)) => {},
+		other => panic!("expected an io error, got {:?}", other),
+	}
+}
@@ -0,0 +1,151 @@
+#![cfg(feature="http")]
+
+extern crate darksky;
+extern crate http;
+
+use darksky::*;
+
+#[test]
+fn test_build_http_request() {
+	let options = Options::default().unit(Unit::Si);
+	let request = build_http_request("token", 37.8267, -122.423, &options).unwrap();
+
+	assert_eq!(request.method(), &http::Method::GET);
+	assert_eq!(request.uri(), "https://api.darksky.net/forecast/token/37.8267,-122.423?units=si");
+
+	match build_http_request(" ", 37.8267, -122.423, &options) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_parse_http_response() {
+	let body = include_str!("fixtures/forecast.json").as_bytes().to_vec();
+	let response = http::Response::builder().status(200).body(body).unwrap();
+	let forecast = parse_http_response(response).unwrap();
+
+	assert_eq!(forecast.timezone, "America/Los_Angeles");
+	assert_eq!(forecast.hourly.and_then(|hourly| hourly.data).map(|data| data.len()), Some(8));
+
+	let response = http::Response::builder().status(200).body("{}").unwrap();
+	assert!(parse_http_response(response).is_err());
+}
+
+#[test]
+fn test_parse_http_response_content_type() {
+	let body = include_str!("fixtures/forecast.json");
+
+	let response = http::Response::builder()
+		.status(200)
+		.header("Content-Type", "application/json; charset=utf-8")
+		.body(body)
+		.unwrap();
+	assert!(parse_http_response(response).is_ok());
+
+	let response = http::Response::builder()
+		.status(200)
+		.header("Content-Type", "text/html; charset=utf-8")
+		.body("<html><body>Down for maintenance</body></html>")
+		.unwrap();
+
+	match parse_http_response(response) {
+		Err(Error::UnexpectedContentType { content_type }) => {
+			assert_eq!(content_type, "text/html; charset=utf-8");
+		},
+		other => panic!("Expected an unexpected content type error, got {:?}", other),
+	}
+
+	let response = http::Response::builder()
+		.status(503)
+		.header("Content-Type", "text/html; charset=utf-8")
+		.body("<html><body>Down for maintenance</body></html>")
+		.unwrap();
+
+	match parse_http_response(response) {
+		Err(why @ Error::Api { code: 503, .. }) => assert!(why.is_transient()),
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_parse_http_response_unexpected_response() {
+	let body = format!(r#"{{"status": "ok", "padding": "{}"}}"#, "x".repeat(1000));
+	let response = http::Response::builder()
+		.status(200)
+		.header("Content-Type", "application/json")
+		.body(body.clone())
+		.unwrap();
+
+	match parse_http_response(response) {
+		Err(Error::UnexpectedResponse { body: snippet, message, status }) => {
+			assert_eq!(status, 200);
+			assert_eq!(snippet, &body[..256]);
+			assert!(message.contains("latitude"), "{}", message);
+		},
+		other => panic!("Expected an unexpected response error, got {:?}", other),
+	}
+
+	let response = http::Response::builder().status(200).body("not json").unwrap();
+	let err = parse_http_response(response).unwrap_err();
+
+	assert_eq!(err.kind(), ErrorKind::Decode);
+	assert!(err.to_string().contains("not json"));
+}
+
+#[test]
+fn test_parse_http_response_api_error() {
+	let response = http::Response::builder()
+		.status(403)
+		.header("Content-Type", "application/json")
+		.body(r#"{"code":403,"error":"daily usage limit exceeded"}"#)
+		.unwrap();
+
+	match parse_http_response(response) {
+		Err(Error::Api { code, message }) => {
+			assert_eq!(code, 403);
+			assert_eq!(message, "daily usage limit exceeded");
+		},
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+
+	let response = http::Response::builder().status(502).body("Bad Gateway\n").unwrap();
+
+	match parse_http_response(response) {
+		Err(Error::Api { code, message }) => {
+			assert_eq!(code, 502);
+			assert_eq!(message, "Bad Gateway");
+		},
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_parse_http_response_with_meta() {
+	let body = include_str!("fixtures/forecast.json");
+
+	let response = http::Response::builder()
+		.status(200)
+		.header("X-Forecast-API-Calls", "42")
+		.header("X-Forecast-Cache", "HIT")
+		.header("X-Response-Time", "55.612ms")
+		.body(body)
+		.unwrap();
+	let response = parse_http_response_with_meta(response).unwrap();
+
+	assert_eq!(response.api_calls, Some(42));
+	assert_eq!(response.cache_hit, Some(true));
+	assert_eq!(response.response_time_ms, Some(56));
+	assert_eq!(response.forecast.timezone, "America/Los_Angeles");
+
+	let response = http::Response::builder()
+		.status(200)
+		.header("X-Response-Time", "unknown")
+		.body(body)
+		.unwrap();
+	let response = parse_http_response_with_meta(response).unwrap();
+
+	assert_eq!(response.api_calls, None);
+	assert_eq!(response.cache_hit, None);
+	assert_eq!(response.response_time_ms, None);
+}
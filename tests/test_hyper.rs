@@ -0,0 +1,68 @@
+#![cfg(feature="hyper")]
+
+extern crate darksky;
+extern crate hyper;
+
+use darksky::*;
+use hyper::net::{HttpStream, NetworkConnector};
+use hyper::Client;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+/// A connector which connects to a local address whatever the requested host,
+/// so that requests to the API are served locally.
+struct LocalConnector(SocketAddr);
+
+impl NetworkConnector for LocalConnector {
+	type Stream = HttpStream;
+
+	fn connect(&self, _host: &str, _port: u16, _scheme: &str) -> hyper::Result<HttpStream> {
+		Ok(HttpStream(TcpStream::connect(self.0)?))
+	}
+}
+
+/// Serves a single chunked HTTP response on a local port, pausing between
+/// each chunk of the body, returning a client which requests it.
+fn serve(chunks: Vec<&'static [u8]>) -> Client {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	thread::spawn(move || {
+		let (mut stream, _) = listener.accept().unwrap();
+		let mut request = [0; 1024];
+		let _ = stream.read(&mut request);
+		stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n").unwrap();
+
+		for chunk in chunks {
+			write!(stream, "{:x}\r\n", chunk.len()).unwrap();
+			stream.write_all(chunk).unwrap();
+			stream.write_all(b"\r\n").unwrap();
+			stream.flush().unwrap();
+			thread::sleep(Duration::from_millis(50));
+		}
+
+		stream.write_all(b"0\r\n\r\n").unwrap();
+	});
+
+	Client::with_connector(LocalConnector(addr))
+}
+
+#[test]
+fn test_get_forecast_with_progress() {
+	let body = include_bytes!("fixtures/forecast.json");
+	let (first, rest) = body.split_at(1000);
+	let (second, third) = rest.split_at(4000);
+	let client = serve(vec![first, second, third]);
+
+	let mut received = vec![];
+	let forecast = client.get_forecast_with_progress("token", 37.8267, -122.423, |o| o, |bytes| {
+		received.push(bytes);
+	}).unwrap();
+
+	assert_eq!(forecast.timezone, "America/Los_Angeles");
+	assert!(received.len() >= 3);
+	assert!(received.windows(2).all(|pair| pair[0] < pair[1]));
+	assert_eq!(received.last(), Some(&(body.len() as u64)));
+}
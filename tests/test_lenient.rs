@@ -0,0 +1,47 @@
+#![cfg(feature="lenient-numbers")]
+
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+#[test]
+fn test_stringified_numbers() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": "1509993277",
+		"temperature": "72.4",
+		"humidity": " 0.81 ",
+		"sunriseTime": "1509978451"
+	}"#).unwrap();
+
+	assert_eq!(datapoint.time, 1509993277);
+	assert_eq!(datapoint.temperature, Some(72.4));
+	assert_eq!(datapoint.humidity, Some(0.81));
+	assert_eq!(datapoint.sunrise_time, Some(1509978451));
+	assert_eq!(datapoint.pressure, None);
+}
+
+#[test]
+fn test_native_numbers() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"temperature": 72,
+		"humidity": 0.81,
+		"dewPoint": null
+	}"#).unwrap();
+
+	assert_eq!(datapoint.time, 1509993277);
+	assert_eq!(datapoint.temperature, Some(72.0));
+	assert_eq!(datapoint.humidity, Some(0.81));
+	assert_eq!(datapoint.dew_point, None);
+}
+
+#[test]
+fn test_non_numeric_string() {
+	let result = serde_json::from_str::<Datapoint>(r#"{
+		"time": 1509993277,
+		"temperature": "warm"
+	}"#);
+
+	assert!(result.is_err());
+}
@@ -0,0 +1,14 @@
+extern crate darksky;
+
+use darksky::limits::*;
+
+#[test]
+fn test_limits() {
+	assert_eq!(FREE_DAILY_CALL_LIMIT, 1000);
+	assert_eq!(FORECAST_DAYS, 7);
+	assert_eq!(MINUTELY_MINUTES, 60);
+	assert_eq!(HOURLY_HOURS, 48);
+	assert_eq!(EXTENDED_HOURLY_HOURS, FORECAST_DAYS * 24);
+	assert_eq!(TIME_MACHINE_YEARS_PAST, 60);
+	assert_eq!(TIME_MACHINE_YEARS_FUTURE, 10);
+}
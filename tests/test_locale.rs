@@ -0,0 +1,39 @@
+#![cfg(feature="locale")]
+
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+fn datapoint(temperature: f64) -> Datapoint {
+	serde_json::from_str(&format!(r#"{{
+		"time": 1509993277,
+		"temperature": {}
+	}}"#, temperature)).unwrap()
+}
+
+#[test]
+fn test_format_temperature_comma_locale() {
+	let datapoint = datapoint(22.43);
+
+	assert_eq!(datapoint.format_temperature(Unit::Si, Language::De), Some("22,4 °C".to_owned()));
+	assert_eq!(datapoint.format_temperature(Unit::Ca, Language::Fr), Some("22,4 °C".to_owned()));
+	assert_eq!(datapoint.format_temperature(Unit::Si, Language::Fi), Some("22,4 °C".to_owned()));
+}
+
+#[test]
+fn test_format_temperature_period_locale() {
+	let datapoint = datapoint(72.36);
+
+	assert_eq!(datapoint.format_temperature(Unit::Us, Language::En), Some("72.4 °F".to_owned()));
+	assert_eq!(datapoint.format_temperature(Unit::Si, Language::Zh), Some("72.4 °C".to_owned()));
+}
+
+#[test]
+fn test_format_temperature_unknown() {
+	let datapoint = datapoint(72.36);
+	assert_eq!(datapoint.format_temperature(Unit::Auto, Language::En), None);
+
+	let datapoint: Datapoint = serde_json::from_str(r#"{"time": 1509993277}"#).unwrap();
+	assert_eq!(datapoint.format_temperature(Unit::Us, Language::En), None);
+}
@@ -0,0 +1,1055 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+fn forecast_at(latitude: f64, longitude: f64) -> Forecast {
+	serde_json::from_str(&format!(r#"{{
+		"latitude": {},
+		"longitude": {},
+		"timezone": "Etc/UTC"
+	}}"#, latitude, longitude)).unwrap()
+}
+
+#[test]
+fn test_from_json() {
+	let json = include_str!("fixtures/forecast.json");
+
+	let from_str = Forecast::from_json_str(json).unwrap();
+	let from_reader = Forecast::from_reader(json.as_bytes()).unwrap();
+	assert_eq!(from_str.timezone, "America/Los_Angeles");
+	assert!(from_str.content_eq(&from_reader));
+
+	match Forecast::from_json_str("{}") {
+		Err(Error::Json(_)) | Err(Error::DecodePath { .. }) => {},
+		other => panic!("Expected a decoding error, got {:?}", other),
+	}
+
+	assert!(Forecast::from_json_str(&json[..json.len() / 2]).is_err());
+}
+
+#[test]
+fn test_distance_to() {
+	let london = forecast_at(51.5074, -0.1278);
+	let km = london.distance_to(48.8566, 2.3522);
+	assert!((km - 343.5).abs() < 1.0, "London to Paris was {} km", km);
+
+	let new_york = forecast_at(40.7128, -74.0060);
+	let km = new_york.distance_to(34.0522, -118.2437);
+	assert!((km - 3935.7).abs() < 5.0, "New York to Los Angeles was {} km", km);
+
+	let miles = new_york.distance_to_miles(34.0522, -118.2437);
+	assert!((miles - 2445.6).abs() < 5.0, "New York to Los Angeles was {} mi", miles);
+}
+
+#[test]
+fn test_bearing_to() {
+	let london = forecast_at(51.5074, -0.1278);
+	assert_eq!(london.bearing_to(48.8566, 2.3522), CompassDirection::SSE);
+
+	let new_york = forecast_at(40.7128, -74.0060);
+	assert_eq!(new_york.bearing_to(34.0522, -118.2437), CompassDirection::W);
+}
+
+#[test]
+fn test_compass_direction_from_bearing() {
+	assert_eq!(CompassDirection::from_bearing(0.0), CompassDirection::N);
+	assert_eq!(CompassDirection::from_bearing(359.0), CompassDirection::N);
+	assert_eq!(CompassDirection::from_bearing(22.5), CompassDirection::NNE);
+	assert_eq!(CompassDirection::from_bearing(-90.0), CompassDirection::W);
+	assert_eq!(CompassDirection::from_bearing(180.0).abbreviation(), "S");
+}
+
+#[test]
+fn test_icon_description() {
+	assert_eq!(Icon::ClearDay.description(), "Clear");
+	assert_eq!(Icon::ClearNight.description(), "Clear");
+	assert_eq!(Icon::Cloudy.description(), "Cloudy");
+	assert_eq!(Icon::Fog.description(), "Foggy");
+	assert_eq!(Icon::Hail.description(), "Hail");
+	assert_eq!(Icon::PartlyCloudyDay.description(), "Partly cloudy");
+	assert_eq!(Icon::PartlyCloudyNight.description(), "Partly cloudy");
+	assert_eq!(Icon::Rain.description(), "Rain");
+	assert_eq!(Icon::Sleet.description(), "Sleet");
+	assert_eq!(Icon::Snow.description(), "Snow");
+	assert_eq!(Icon::Thunderstorm.description(), "Thunderstorms");
+	assert_eq!(Icon::Tornado.description(), "Tornado");
+	assert_eq!(Icon::Wind.description(), "Windy");
+}
+
+#[test]
+fn test_icon_emoji() {
+	assert_eq!(Icon::ClearDay.emoji(), "☀️");
+	assert_eq!(Icon::ClearNight.emoji(), "🌙");
+	assert_eq!(Icon::Cloudy.emoji(), "☁️");
+	assert_eq!(Icon::Fog.emoji(), "🌫️");
+	assert_eq!(Icon::Hail.emoji(), "🧊");
+	assert_eq!(Icon::PartlyCloudyDay.emoji(), "⛅");
+	assert_eq!(Icon::PartlyCloudyNight.emoji(), "☁️");
+	assert_eq!(Icon::Rain.emoji(), "🌧️");
+	assert_eq!(Icon::Sleet.emoji(), "🌨️");
+	assert_eq!(Icon::Snow.emoji(), "❄️");
+	assert_eq!(Icon::Thunderstorm.emoji(), "⛈️");
+	assert_eq!(Icon::Tornado.emoji(), "🌪️");
+	assert_eq!(Icon::Wind.emoji(), "💨");
+}
+
+#[test]
+fn test_accessible_label() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"icon": "partly-cloudy-day",
+		"summary": "Parcialmente nublado",
+		"temperature": 71.6
+	}"#).unwrap();
+
+	assert_eq!(datapoint.accessible_label(), Some("Partly cloudy, 72°".to_owned()));
+	assert_eq!(datapoint.summary, Some("Parcialmente nublado".to_owned()));
+
+	let datapoint: Datapoint = serde_json::from_str(r#"{"time": 1509993277}"#).unwrap();
+	assert_eq!(datapoint.accessible_label(), None);
+}
+
+#[test]
+fn test_alerts_array() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"alerts": [
+			{"title": "Flood Watch", "description": "...", "uri": "https://alerts.weather.gov/1"},
+			{"title": "Wind Advisory", "description": "...", "uri": "https://alerts.weather.gov/2", "expires": 1509993277}
+		],
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	assert_eq!(forecast.alerts.len(), 2);
+	assert_eq!(forecast.alerts[1].title, "Wind Advisory");
+	assert_eq!(forecast.alerts[1].expires, Some(1509993277));
+}
+
+#[test]
+fn test_alerts_single_object() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"alerts": {"title": "Flood Watch", "description": "...", "uri": "https://alerts.weather.gov/1"},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	assert_eq!(forecast.alerts.len(), 1);
+	assert_eq!(forecast.alerts[0].title, "Flood Watch");
+}
+
+#[test]
+fn test_alert_details() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	let alert = &forecast.alerts[0];
+
+	assert_eq!(alert.severity, Some(AlertSeverity::Watch));
+	assert_eq!(alert.regions, Some(vec!["Mason".to_owned()]));
+	assert_eq!(alert.time, Some(1509993360));
+
+	let alert: Alert = serde_json::from_str(r#"{"title": "", "description": "", "uri": "", "severity": "warning"}"#).unwrap();
+	assert_eq!(alert.severity, Some(AlertSeverity::Warning));
+	assert_eq!(alert.regions, None);
+	assert_eq!(alert.time, None);
+
+	assert!(AlertSeverity::Advisory < AlertSeverity::Watch);
+	assert!(AlertSeverity::Watch < AlertSeverity::Warning);
+}
+
+#[test]
+fn test_alerts_missing() {
+	let forecast = forecast_at(37.8267, -122.423);
+
+	assert!(forecast.alerts.is_empty());
+}
+
+fn conditions(time: u64, temperature: f64, temperature_error: f64) -> Forecast {
+	serde_json::from_str(&format!(r#"{{
+		"currently": {{
+			"time": {0},
+			"icon": "rain",
+			"summary": "Light Rain",
+			"temperature": {1},
+			"temperatureError": {2}
+		}},
+		"hourly": {{
+			"icon": "rain",
+			"data": [{{"time": {0}, "icon": "rain", "temperature": {1}}}]
+		}},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}}"#, time, temperature, temperature_error)).unwrap()
+}
+
+#[test]
+fn test_content_eq_ignores_timestamps() {
+	let cached = conditions(1509993277, 58.3, 1.2);
+	let fresh = conditions(1509996877, 58.3, 2.5);
+
+	assert!(cached.content_eq(&fresh));
+}
+
+#[test]
+fn test_content_eq_tolerance() {
+	let cached = conditions(1509993277, 58.3, 1.2);
+	let fresh = conditions(1509993277, 58.5, 1.2);
+
+	assert!(!cached.content_eq(&fresh));
+	assert!(cached.content_eq_within(&fresh, 0.5));
+}
+
+#[test]
+fn test_is_precipitating() {
+	let clear: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"precipIntensity": 0,
+		"precipProbability": 0
+	}"#).unwrap();
+	assert!(!clear.is_precipitating());
+	assert_eq!(clear.precip_type_or_none(), None);
+
+	let rainy: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"precipIntensity": 0.0089,
+		"precipProbability": 0.42,
+		"precipType": "rain"
+	}"#).unwrap();
+	assert!(rainy.is_precipitating());
+	assert_eq!(rainy.precip_type_or_none(), Some(PrecipitationType::Rain));
+
+	let stale_type: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"precipProbability": 0,
+		"precipType": "snow"
+	}"#).unwrap();
+	assert!(!stale_type.is_precipitating());
+	assert_eq!(stale_type.precip_type, Some(PrecipitationType::Snow));
+	assert_eq!(stale_type.precip_type_or_none(), None);
+}
+
+#[test]
+fn test_pressure_conversion() {
+	let datapoint = Datapoint {
+		pressure: Some(1013.25),
+		..Datapoint::default()
+	};
+
+	assert_eq!(datapoint.pressure_hpa(), Some(1013.25));
+	assert!((datapoint.pressure_inhg().unwrap() - 29.9213).abs() < 0.0001);
+
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	let si = forecast.clone().convert_units(Unit::Us, Unit::Si).unwrap();
+	let (us, si) = (forecast.currently.unwrap(), si.currently.unwrap());
+	assert_eq!(us.pressure_inhg(), si.pressure_inhg());
+
+	assert_eq!(Datapoint::default().pressure_hpa(), None);
+	assert_eq!(Datapoint::default().pressure_inhg(), None);
+}
+
+#[test]
+fn test_visibility_conversion() {
+	let datapoint = Datapoint {
+		visibility: Some(10.0),
+		..Datapoint::default()
+	};
+
+	assert!((datapoint.visibility_km(Unit::Us).unwrap() - 16.0934).abs() < 0.0001);
+	assert!((datapoint.visibility_km(Unit::Uk2).unwrap() - 16.0934).abs() < 0.0001);
+	assert_eq!(datapoint.visibility_km(Unit::Si), Some(10.0));
+	assert_eq!(datapoint.visibility_km(Unit::Ca), Some(10.0));
+	assert!((datapoint.visibility_miles(Unit::Si).unwrap() - 6.2137).abs() < 0.0001);
+	assert_eq!(datapoint.visibility_miles(Unit::Us), Some(10.0));
+
+	assert_eq!(datapoint.visibility_km(Unit::Auto), None);
+	assert_eq!(datapoint.visibility_miles(Unit::Auto), None);
+	assert_eq!(Datapoint::default().visibility_km(Unit::Si), None);
+}
+
+#[test]
+fn test_wind_cardinal() {
+	let wind = |bearing| Datapoint {
+		wind_bearing: Some(bearing),
+		..Datapoint::default()
+	};
+
+	assert_eq!(wind(0.0).wind_cardinal(), Some("N"));
+	assert_eq!(wind(11.0).wind_cardinal(), Some("N"));
+	assert_eq!(wind(22.5).wind_cardinal(), Some("NNE"));
+	assert_eq!(wind(190.0).wind_cardinal(), Some("S"));
+	assert_eq!(wind(337.5).wind_cardinal(), Some("NNW"));
+	assert_eq!(wind(348.75).wind_cardinal(), Some("N"));
+	assert_eq!(wind(359.9).wind_cardinal(), Some("N"));
+	assert_eq!(wind(360.0).wind_cardinal(), Some("N"));
+	assert_eq!(Datapoint::default().wind_cardinal(), None);
+}
+
+#[test]
+fn test_moon_phase_from_fraction() {
+	assert_eq!(MoonPhase::from_fraction(0.0), MoonPhase::NewMoon);
+	assert_eq!(MoonPhase::from_fraction(0.1), MoonPhase::WaxingCrescent);
+	assert_eq!(MoonPhase::from_fraction(0.25), MoonPhase::FirstQuarter);
+	assert_eq!(MoonPhase::from_fraction(0.4), MoonPhase::WaxingGibbous);
+	assert_eq!(MoonPhase::from_fraction(0.5), MoonPhase::FullMoon);
+	assert_eq!(MoonPhase::from_fraction(0.6), MoonPhase::WaningGibbous);
+	assert_eq!(MoonPhase::from_fraction(0.75), MoonPhase::LastQuarter);
+	assert_eq!(MoonPhase::from_fraction(0.9), MoonPhase::WaningCrescent);
+	assert_eq!(MoonPhase::from_fraction(0.95), MoonPhase::NewMoon);
+	assert_eq!(MoonPhase::from_fraction(1.0), MoonPhase::NewMoon);
+	assert_eq!(MoonPhase::from_fraction(-0.25), MoonPhase::LastQuarter);
+}
+
+#[test]
+fn test_moon_phase_name() {
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	let daily = &forecast.daily.as_ref().unwrap().data.as_ref().unwrap()[0];
+
+	let phase = daily.moon_phase_name().unwrap();
+	assert_eq!(phase, MoonPhase::from_fraction(daily.moon_phase.unwrap()));
+	assert_eq!(daily.moon_phase_emoji(), Some(phase.emoji()));
+
+	let currently = forecast.currently.as_ref().unwrap();
+	assert_eq!(currently.moon_phase_name(), None);
+	assert_eq!(currently.moon_phase_emoji(), None);
+}
+
+#[test]
+fn test_datapoint_block_heuristics() {
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let currently = forecast.currently.as_ref().unwrap();
+	assert!(currently.is_currently());
+	assert!(!currently.is_daily());
+
+	for datapoint in forecast.daily_points() {
+		assert!(datapoint.is_daily());
+		assert!(!datapoint.is_currently());
+	}
+
+	for datapoint in forecast.hourly_points().iter().chain(forecast.minutely_points()) {
+		assert!(!datapoint.is_daily());
+		assert!(!datapoint.is_currently());
+	}
+}
+
+#[test]
+fn test_forecast_eq() {
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	let reparsed = Forecast::from_json_str(&serde_json::to_string(&forecast).unwrap()).unwrap();
+
+	assert_eq!(forecast, reparsed);
+	assert_eq!(conditions(1509993277, 58.3, 1.2), conditions(1509993277, 58.3, 1.2));
+	assert_ne!(conditions(1509993277, 58.3, 1.2), conditions(1509996877, 58.3, 1.2));
+	assert_ne!(conditions(1509993277, 58.3, 1.2), conditions(1509993277, 58.5, 1.2));
+}
+
+#[test]
+fn test_display_rows() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"summary": "Drizzle",
+		"icon": "rain",
+		"temperature": 58.31,
+		"apparentTemperature": 57.9,
+		"humidity": 0.87,
+		"precipProbability": 0.6,
+		"precipType": "rain",
+		"precipIntensity": 0.0123,
+		"windSpeed": 6.14,
+		"windGust": 12.07,
+		"windBearing": 200,
+		"pressure": 1016.42
+	}"#).unwrap();
+
+	assert_eq!(datapoint.display_rows(Unit::Us), vec![
+		("Summary", "Drizzle".to_owned()),
+		("Temperature", "58.3 °F".to_owned()),
+		("Feels like", "57.9 °F".to_owned()),
+		("Humidity", "87%".to_owned()),
+		("Precipitation chance", "60%".to_owned()),
+		("Precipitation type", "Rain".to_owned()),
+		("Precipitation intensity", "0.012 in/h".to_owned()),
+		("Wind speed", "6.1 mph".to_owned()),
+		("Wind gust", "12.1 mph".to_owned()),
+		("Wind bearing", "200° (SSW)".to_owned()),
+		("Pressure", "1016.4 mbar".to_owned()),
+	]);
+}
+
+#[test]
+fn test_serialize_omits_absent_fields() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"temperature": 58.3,
+		"humidity": null
+	}"#).unwrap();
+
+	let json = serde_json::to_string(&datapoint).unwrap();
+	assert_eq!(json, r#"{"temperature":58.3,"time":1509993277}"#);
+
+	let forecast = forecast_at(37.8267, -122.423);
+	let json = serde_json::to_string(&forecast).unwrap();
+	assert_eq!(json, r#"{"latitude":37.8267,"longitude":-122.423,"timezone":"Etc/UTC"}"#);
+
+	let parsed: Forecast = serde_json::from_str(&json).unwrap();
+	assert!(parsed.currently.is_none());
+	assert!(parsed.alerts.is_empty());
+}
+
+#[test]
+fn test_system_time() {
+	use std::time::{Duration, UNIX_EPOCH};
+
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509993277,
+		"sunriseTime": 1509978451
+	}"#).unwrap();
+
+	assert_eq!(datapoint.system_time(), Some(UNIX_EPOCH + Duration::from_secs(1509993277)));
+	assert_eq!(datapoint.sunrise_system_time(), Some(UNIX_EPOCH + Duration::from_secs(1509978451)));
+	assert_eq!(datapoint.sunset_system_time(), None);
+
+	let alert: Alert = serde_json::from_str(r#"{
+		"title": "Flood Watch",
+		"description": "...",
+		"uri": "https://alerts.weather.gov/1",
+		"expires": 0
+	}"#).unwrap();
+
+	assert_eq!(alert.expires_system_time(), Some(UNIX_EPOCH));
+	assert_eq!(unix_to_system_time(u64::MAX), None);
+}
+
+#[test]
+fn test_wind_chill_and_heat_index() {
+	assert!((wind_chill(5.0, 30.0).unwrap() - -19.1).abs() < 0.1);
+	assert_eq!(wind_chill(60.0, 30.0), None);
+	assert!((heat_index(96.0, 0.65).unwrap() - 121.0).abs() < 1.0);
+	assert_eq!(heat_index(70.0, 0.65), None);
+}
+
+#[test]
+fn test_fill_apparent_temperature() {
+	let mut forecast: Forecast = serde_json::from_str(r#"{
+		"currently": {"time": 1509993277, "temperature": -15.0, "humidity": 0.5, "windSpeed": 13.4, "apparentTemperature": -20.0},
+		"hourly": {
+			"data": [
+				{"time": 1509993277, "temperature": -15.0, "humidity": 0.5, "windSpeed": 13.4},
+				{"time": 1509996877, "temperature": -15.0, "humidity": 0.5},
+				{"time": 1510000477, "temperature": 18.0, "humidity": 0.5, "windSpeed": 2.0}
+			]
+		},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	assert_eq!(forecast.fill_apparent_temperature(Unit::Si), 2);
+
+	let currently = forecast.currently.as_ref().unwrap();
+	assert_eq!(currently.apparent_temperature, Some(-20.0));
+	assert!(!currently.apparent_temperature_estimated);
+
+	let hourly = forecast.hourly.as_ref().unwrap().data.as_ref().unwrap();
+	assert!((hourly[0].apparent_temperature.unwrap() - -28.4).abs() < 0.1);
+	assert!(hourly[0].apparent_temperature_estimated);
+	assert_eq!(hourly[1].apparent_temperature, None);
+	assert!((hourly[2].apparent_temperature.unwrap() - 18.0).abs() < 1e-9);
+	assert!(hourly[2].apparent_temperature_estimated);
+}
+
+#[test]
+fn test_sun_events() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"daily": {
+			"data": [{"time": 1509955200, "sunriseTime": 1509978451, "sunsetTime": 1510016000}]
+		},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	let events = forecast.sun_events().unwrap();
+	assert_eq!(events.sunrise, 1509978451);
+	assert_eq!(events.sunset, 1510016000);
+	assert_eq!(events.morning_blue_hour, TimeWindow { start: 1509978451 - 1800, end: 1509978451 });
+	assert_eq!(events.morning_golden_hour, TimeWindow { start: 1509978451, end: 1509978451 + 3600 });
+	assert_eq!(events.evening_golden_hour, TimeWindow { start: 1510016000 - 3600, end: 1510016000 });
+	assert_eq!(events.evening_blue_hour, TimeWindow { start: 1510016000, end: 1510016000 + 1800 });
+}
+
+#[test]
+fn test_sun_events_saturate() {
+	let events = SunEvents::new(u64::MAX, u64::MAX);
+
+	assert_eq!(events.morning_golden_hour, TimeWindow { start: u64::MAX, end: u64::MAX });
+	assert_eq!(events.evening_blue_hour, TimeWindow { start: u64::MAX, end: u64::MAX });
+}
+
+#[test]
+fn test_sun_events_polar() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"daily": {"data": [{"time": 1509955200}]},
+		"latitude": 78.2232,
+		"longitude": 15.6267,
+		"timezone": "Arctic/Longyearbyen"
+	}"#).unwrap();
+
+	assert_eq!(forecast.sun_events(), None);
+	assert_eq!(forecast_at(78.2232, 15.6267).sun_events(), None);
+}
+
+#[test]
+fn test_block() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"currently": {"time": 1509993277, "icon": "rain", "summary": "Drizzle"},
+		"minutely": {"summary": "Drizzle for the hour."},
+		"hourly": {"summary": "Rain until tomorrow morning."},
+		"daily": {"summary": "Rain throughout the week."},
+		"flags": {"units": "us"},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	let currently = forecast.block(Block::Currently).unwrap();
+	assert_eq!(currently.icon, Some(Icon::Rain));
+	assert_eq!(currently.summary, Some("Drizzle".to_owned()));
+	assert_eq!(currently.data.as_ref().unwrap()[0].time, 1509993277);
+
+	let summary = |block| forecast.block(block).and_then(|b| b.summary.clone());
+	assert_eq!(summary(Block::Minutely), Some("Drizzle for the hour.".to_owned()));
+	assert_eq!(summary(Block::Hourly), Some("Rain until tomorrow morning.".to_owned()));
+	assert_eq!(summary(Block::Daily), Some("Rain throughout the week.".to_owned()));
+	assert!(forecast.block(Block::Flags).is_none());
+	assert!(forecast.block(Block::Alerts).is_none());
+
+	assert!(forecast_at(37.8267, -122.423).block(Block::Daily).is_none());
+}
+
+#[test]
+fn test_unit_matches() {
+	let with_units = |units: &str| -> Forecast {
+		serde_json::from_str(&format!(r#"{{
+			"flags": {{"units": "{}"}},
+			"latitude": 37.8267,
+			"longitude": -122.423,
+			"timezone": "America/Los_Angeles"
+		}}"#, units)).unwrap()
+	};
+
+	let si = with_units("si");
+	assert!(si.unit_matches(Unit::Si));
+	assert!(si.unit_matches(Unit::Auto));
+	assert!(!si.unit_matches(Unit::Us));
+
+	let us = with_units("us");
+	assert!(us.unit_matches(Unit::Us));
+	assert!(!us.unit_matches(Unit::Si));
+
+	let missing = forecast_at(37.8267, -122.423);
+	assert!(!missing.unit_matches(Unit::Us));
+	assert!(!missing.unit_matches(Unit::Auto));
+}
+
+#[test]
+fn test_resolved_unit() {
+	let with_units = |units: &str| -> Forecast {
+		serde_json::from_str(&format!(r#"{{
+			"flags": {{"units": "{}"}},
+			"latitude": 37.8267,
+			"longitude": -122.423,
+			"timezone": "America/Los_Angeles"
+		}}"#, units)).unwrap()
+	};
+
+	assert_eq!(with_units("ca").resolved_unit(), Some(Unit::Ca));
+	assert_eq!(with_units("si").resolved_unit(), Some(Unit::Si));
+	assert_eq!(with_units("uk2").resolved_unit(), Some(Unit::Uk2));
+	assert_eq!(with_units("us").resolved_unit(), Some(Unit::Us));
+	assert_eq!(with_units("auto").resolved_unit(), None);
+	assert_eq!(with_units("metric").resolved_unit(), None);
+	assert_eq!(forecast_at(37.8267, -122.423).resolved_unit(), None);
+}
+
+#[test]
+fn test_points() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	assert_eq!(forecast.hourly_points().len(), 8);
+	assert_eq!(forecast.hourly_points()[0].time, forecast.hourly.as_ref().unwrap().points()[0].time);
+	assert_eq!(forecast.daily_points().len(), forecast.daily.as_ref().and_then(|daily| daily.data.as_ref()).unwrap().len());
+	assert_eq!(forecast.minutely_points().len(), forecast.minutely.as_ref().and_then(|minutely| minutely.data.as_ref()).unwrap().len());
+
+	let empty = forecast_at(37.8267, -122.423);
+	assert!(empty.minutely_points().is_empty());
+	assert!(empty.hourly_points().is_empty());
+	assert!(empty.daily_points().is_empty());
+
+	let block: Datablock = serde_json::from_str(r#"{"summary": "Clear"}"#).unwrap();
+	assert!(block.points().is_empty());
+}
+
+#[test]
+fn test_without_blocks() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let trimmed = forecast.without_blocks(&[Block::Currently]);
+	assert!(trimmed.currently.is_none());
+	assert!(trimmed.daily.is_some());
+
+	let trimmed = forecast.without_blocks(&[Block::Daily]);
+	assert!(trimmed.daily.is_none());
+	assert!(trimmed.hourly.is_some());
+
+	let trimmed = forecast.without_blocks(&[Block::Flags]);
+	assert!(trimmed.flags.is_none());
+	assert!(trimmed.currently.is_some());
+
+	let trimmed = forecast.without_blocks(&[Block::Hourly]);
+	assert!(trimmed.hourly.is_none());
+	assert!(trimmed.minutely.is_some());
+
+	let trimmed = forecast.without_blocks(&[Block::Minutely]);
+	assert!(trimmed.minutely.is_none());
+	assert!(trimmed.currently.is_some());
+
+	let trimmed = forecast.without_blocks(&[Block::Hourly, Block::Minutely]);
+	assert!(trimmed.hourly.is_none());
+	assert!(trimmed.minutely.is_none());
+	assert_eq!(trimmed.alerts.len(), forecast.alerts.len());
+
+	let trimmed = forecast.without_blocks(&[Block::Alerts]);
+	assert!(trimmed.alerts.is_empty());
+	assert!(trimmed.currently.is_some());
+
+	assert!(forecast.without_blocks(&[]).content_eq(&forecast));
+	assert!(forecast.hourly.is_some());
+}
+
+#[test]
+fn test_dew_point_spread() {
+	let datapoint = |json: &str| -> Datapoint { serde_json::from_str(json).unwrap() };
+
+	let foggy = datapoint(r#"{"time": 1509993277, "temperature": 52.0, "dewPoint": 51.0}"#);
+	assert_eq!(foggy.dew_point_spread(), Some(1.0));
+	assert_eq!(foggy.fog_likely(), Some(true));
+
+	let dry = datapoint(r#"{"time": 1509993277, "temperature": 75.0, "dewPoint": 45.0}"#);
+	assert_eq!(dry.dew_point_spread(), Some(30.0));
+	assert_eq!(dry.fog_likely(), Some(false));
+
+	let missing = datapoint(r#"{"time": 1509993277, "temperature": 75.0}"#);
+	assert_eq!(missing.dew_point_spread(), None);
+	assert_eq!(missing.fog_likely(), None);
+}
+
+#[test]
+fn test_display_temperature() {
+	let datapoint = |json: &str| -> Datapoint { serde_json::from_str(json).unwrap() };
+
+	let both = datapoint(r#"{"time": 1509993277, "temperature": 58.3, "apparentTemperature": 57.9}"#);
+	assert_eq!(both.display_temperature(), Some(57.9));
+	assert_eq!(both.display_temperature_with_source(), Some((57.9, true)));
+
+	let actual = datapoint(r#"{"time": 1509993277, "temperature": 58.3}"#);
+	assert_eq!(actual.display_temperature(), Some(58.3));
+	assert_eq!(actual.display_temperature_with_source(), Some((58.3, false)));
+
+	let neither = datapoint(r#"{"time": 1509993277}"#);
+	assert_eq!(neither.display_temperature(), None);
+	assert_eq!(neither.display_temperature_with_source(), None);
+}
+
+#[test]
+fn test_temperature_conversions() {
+	let cases = [
+		(Unit::Us, 32.0, 0.0, 32.0),
+		(Unit::Us, 212.0, 100.0, 212.0),
+		(Unit::Us, -40.0, -40.0, -40.0),
+		(Unit::Si, 0.0, 0.0, 32.0),
+		(Unit::Si, 37.0, 37.0, 98.6),
+		(Unit::Ca, -40.0, -40.0, -40.0),
+		(Unit::Uk2, 100.0, 100.0, 212.0),
+	];
+
+	for &(unit, temperature, celsius, fahrenheit) in &cases {
+		let datapoint = Datapoint {
+			temperature: Some(temperature),
+			..Datapoint::default()
+		};
+
+		assert!((datapoint.temperature_celsius(unit).unwrap() - celsius).abs() < 1e-9);
+		assert!((datapoint.temperature_fahrenheit(unit).unwrap() - fahrenheit).abs() < 1e-9);
+		assert_eq!(datapoint.temperature_celsius(Unit::Auto), None);
+		assert_eq!(datapoint.temperature_fahrenheit(Unit::Auto), None);
+	}
+
+	// Metric temperatures are already in degrees Celsius, so pass through
+	// exactly.
+	for &unit in &[Unit::Ca, Unit::Si, Unit::Uk2] {
+		let datapoint = Datapoint {
+			temperature: Some(21.7),
+			..Datapoint::default()
+		};
+
+		assert_eq!(datapoint.temperature_celsius(unit), Some(21.7));
+	}
+
+	assert_eq!(Datapoint::default().temperature_celsius(Unit::Us), None);
+	assert_eq!(Datapoint::default().temperature_fahrenheit(Unit::Si), None);
+}
+
+#[test]
+fn test_into_parts() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	let parts = forecast.into_parts();
+
+	assert_eq!(parts.alerts.len(), 1);
+	assert_eq!(parts.currently.map(|currently| currently.time), Some(1509991500));
+	assert_eq!(parts.daily.and_then(|daily| daily.data).map(|data| data.len()), Some(3));
+	assert_eq!(parts.flags.and_then(|flags| flags.units), Some("us".to_owned()));
+	assert_eq!(parts.hourly.and_then(|hourly| hourly.data).map(|data| data.len()), Some(8));
+	assert_eq!(parts.minutely.and_then(|minutely| minutely.data).map(|data| data.len()), Some(20));
+	assert_eq!(parts.location, Location {
+		latitude: 37.8267,
+		longitude: -122.4233,
+		offset: Some(-8.0),
+		timezone: "America/Los_Angeles".to_owned(),
+	});
+}
+
+#[test]
+fn test_convert_units() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	let close = |a: Option<f64>, b: f64| (a.unwrap() - b).abs() < 0.01;
+
+	let si = forecast.convert_units(Unit::Us, Unit::Si).unwrap();
+	let currently = si.currently.as_ref().unwrap();
+	assert!(close(currently.temperature, 14.61));
+	assert!(close(currently.apparent_temperature, 14.39));
+	assert!(close(currently.wind_speed, 2.73));
+	assert!(close(currently.visibility, 14.81));
+	assert!(close(currently.precip_intensity, 0.0254));
+	assert_eq!(currently.pressure, Some(1016.2));
+	assert_eq!(currently.humidity, Some(0.74));
+	assert_eq!(si.flags.as_ref().and_then(|flags| flags.units.clone()), Some("si".to_owned()));
+
+	let today = &si.daily.as_ref().unwrap().data.as_ref().unwrap()[0];
+	assert!(close(today.temperature_max, 16.22));
+	assert!(close(today.temperature_min, 10.22));
+
+	let ca = forecast.convert_units(Unit::Us, Unit::Ca).unwrap();
+	assert!(close(ca.currently.as_ref().unwrap().wind_speed, 9.82));
+
+	let uk2 = forecast.convert_units(Unit::Us, Unit::Uk2).unwrap();
+	assert!(close(uk2.currently.as_ref().unwrap().wind_speed, 6.1));
+	assert!(close(uk2.currently.as_ref().unwrap().visibility, 9.2));
+
+	let round_trip = si.convert_units(Unit::Si, Unit::Us).unwrap();
+	assert!(round_trip.content_eq(&forecast));
+
+	assert!(forecast.convert_units(Unit::Auto, Unit::Si).is_none());
+	assert!(forecast.convert_units(Unit::Us, Unit::Auto).is_none());
+}
+
+#[test]
+fn test_serialize_deterministic() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let first = serde_json::to_vec(&forecast).unwrap();
+	let second = serde_json::to_vec(&forecast.clone()).unwrap();
+	assert_eq!(first, second);
+
+	let reparsed: Forecast = serde_json::from_slice(&first).unwrap();
+	assert_eq!(serde_json::to_vec(&reparsed).unwrap(), first);
+}
+
+#[test]
+fn test_next_hour_precipitation() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"minutely": {"data": [
+			{"time": 1509993240, "precipIntensity": 0},
+			{"time": 1509993300},
+			{"time": 1509993360, "precipIntensity": 0.012},
+			{"time": 1509993420, "precipIntensity": 0.034}
+		]},
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	assert_eq!(forecast.next_hour_precipitation(), vec![
+		(1509993240, 0.0),
+		(1509993360, 0.012),
+		(1509993420, 0.034),
+	]);
+	assert!(forecast_at(37.8267, -122.423).next_hour_precipitation().is_empty());
+}
+
+#[test]
+fn test_weekly_temperature_range() {
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+	assert_eq!(forecast.weekly_temperature_range(), Some((48.7, 63.5)));
+
+	let sparse = Forecast::from_json_str(r#"{
+		"daily": {"data": [
+			{"time": 1509955200, "temperatureMin": 41.2},
+			{"time": 1510041600, "temperatureMax": 66.1},
+			{"time": 1510128000},
+			{"time": 1510214400, "temperatureMin": 44.8, "temperatureMax": 59.0}
+		]},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+	assert_eq!(sparse.weekly_temperature_range(), Some((41.2, 66.1)));
+
+	let lows_only = Forecast::from_json_str(r#"{
+		"daily": {"data": [{"time": 1509955200, "temperatureMin": 41.2}]},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+	assert_eq!(lows_only.weekly_temperature_range(), None);
+	assert_eq!(forecast_at(37.8267, -122.423).weekly_temperature_range(), None);
+}
+
+#[test]
+fn test_rain_expected_today() {
+	let rainy: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	assert_eq!(rainy.max_precip_probability_today(), Some(0.8));
+	assert_eq!(rainy.rain_expected_today(0.5), Some(true));
+	assert_eq!(rainy.rain_expected_today(0.9), Some(false));
+
+	let dry: Forecast = serde_json::from_str(r#"{
+		"hourly": {"data": [
+			{"time": 1509955200, "precipProbability": 0.1},
+			{"time": 1509998400, "precipProbability": 0.2},
+			{"time": 1510045200, "precipProbability": 0.9}
+		]},
+		"daily": {"data": [{"time": 1509955200, "precipProbability": 0.05}]},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+	assert_eq!(dry.max_precip_probability_today(), Some(0.2));
+	assert_eq!(dry.rain_expected_today(0.5), Some(false));
+
+	let hourly_only = dry.without_blocks(&[Block::Daily]);
+	assert_eq!(hourly_only.max_precip_probability_today(), Some(0.2));
+
+	assert_eq!(forecast_at(37.8267, -122.423).rain_expected_today(0.5), None);
+}
+
+#[test]
+fn test_null_blocks() {
+	let parse = |fields: &str| -> Forecast {
+		serde_json::from_str(&format!(r#"{{
+			{}
+			"latitude": 37.8267,
+			"longitude": -122.423,
+			"timezone": "America/Los_Angeles"
+		}}"#, fields)).unwrap()
+	};
+
+	let null = parse(r#"
+		"currently": null,
+		"minutely": null,
+		"hourly": null,
+		"daily": null,
+		"flags": null,
+		"offset": null,
+	"#);
+	assert!(null.currently.is_none());
+	assert!(null.minutely.is_none());
+	assert!(null.hourly.is_none());
+	assert!(null.daily.is_none());
+	assert!(null.flags.is_none());
+	assert!(null.offset.is_none());
+
+	let absent = parse("");
+	assert!(absent.currently.is_none());
+	assert!(absent.minutely.is_none());
+	assert!(absent.hourly.is_none());
+	assert!(absent.daily.is_none());
+	assert!(absent.flags.is_none());
+	assert!(absent.offset.is_none());
+
+	let populated = parse(r#"
+		"currently": {"time": 1509993277},
+		"minutely": {"data": []},
+		"hourly": {"data": []},
+		"daily": {"data": []},
+		"flags": {"units": "us"},
+		"offset": -8,
+	"#);
+	assert!(populated.currently.is_some());
+	assert!(populated.minutely.is_some());
+	assert!(populated.hourly.is_some());
+	assert!(populated.daily.is_some());
+	assert_eq!(populated.flags.and_then(|flags| flags.units), Some("us".to_owned()));
+	assert_eq!(populated.offset, Some(-8.0));
+}
+
+#[test]
+fn test_utc_offset_seconds() {
+	let with_offset = |offset: Option<f64>| {
+		let mut forecast = forecast_at(37.8267, -122.423);
+		forecast.offset = offset;
+		forecast
+	};
+
+	assert_eq!(with_offset(Some(-8.0)).utc_offset_seconds(), Some(-28800));
+	assert_eq!(with_offset(Some(5.75)).utc_offset_seconds(), Some(20700));
+	assert_eq!(with_offset(Some(0.0)).utc_offset_seconds(), Some(0));
+	assert_eq!(with_offset(None).utc_offset_seconds(), None);
+	assert_eq!(with_offset(Some(24.0)).utc_offset_seconds(), None);
+	assert_eq!(with_offset(Some(f64::NAN)).utc_offset_seconds(), None);
+}
+
+#[test]
+fn test_coordinate_wraps_longitude() {
+	let longitude = |longitude: f64| Coordinate::new(0.0, longitude).unwrap().longitude();
+
+	assert_eq!(longitude(181.0), -179.0);
+	assert_eq!(longitude(-181.0), 179.0);
+	assert_eq!(longitude(540.0), -180.0);
+	assert_eq!(longitude(180.0), -180.0);
+	assert_eq!(longitude(-180.0), -180.0);
+	assert_eq!(longitude(-122.423), -122.423);
+}
+
+#[test]
+fn test_coordinate_rejects_latitude() {
+	assert_eq!(Coordinate::new(90.0, 0.0).unwrap().latitude(), 90.0);
+	assert_eq!(Coordinate::new(-90.0, 0.0).unwrap().latitude(), -90.0);
+
+	for &(latitude, longitude) in &[(90.5, 0.0), (-91.0, 0.0), (f64::NAN, 0.0), (0.0, f64::INFINITY)] {
+		match Coordinate::new(latitude, longitude) {
+			Err(Error::InvalidLocation(..)) => {},
+			other => panic!("Expected an invalid location error, got {:?}", other),
+		}
+	}
+}
+
+#[test]
+fn test_uv_index() {
+	let datapoint: Datapoint = serde_json::from_str(r#"{
+		"time": 1509955200,
+		"uvIndex": 4,
+		"uvIndexTime": 1509994800
+	}"#).unwrap();
+
+	assert_eq!(datapoint.uv_index, Some(4.0));
+	assert_eq!(datapoint.uv_index_time, Some(1509994800));
+
+	let json = serde_json::to_string(&datapoint).unwrap();
+	assert_eq!(json, r#"{"time":1509955200,"uvIndexTime":1509994800,"uvIndex":4.0}"#);
+}
+
+#[test]
+fn test_wind_gust() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let currently = forecast.currently.as_ref().unwrap();
+	assert_eq!(currently.wind_gust, Some(11.2));
+	assert_eq!(currently.wind_gust_time, None);
+
+	let today = &forecast.daily.as_ref().unwrap().data.as_ref().unwrap()[0];
+	assert_eq!(today.wind_gust, Some(14.1));
+	assert_eq!(today.wind_gust_time, Some(1510027200));
+
+	let si = forecast.convert_units(Unit::Us, Unit::Si).unwrap();
+	let gust = si.currently.as_ref().unwrap().wind_gust.unwrap();
+	assert!((gust - 5.01).abs() < 0.01);
+}
+
+#[test]
+fn test_polar_helpers() {
+	let north = forecast_at(90.0, 0.0);
+	let south = forecast_at(-90.0, 0.0);
+
+	assert_eq!(north.bearing_to(37.8267, -122.423), CompassDirection::S);
+	assert_eq!(north.bearing_to(-90.0, 0.0), CompassDirection::S);
+	assert_eq!(south.bearing_to(37.8267, -122.423), CompassDirection::N);
+	assert_eq!(forecast_at(37.8267, -122.423).bearing_to(90.0, 0.0), CompassDirection::N);
+	assert_eq!(forecast_at(37.8267, -122.423).bearing_to(-90.0, 0.0), CompassDirection::S);
+
+	let pole_to_pole = north.distance_to(-90.0, 0.0);
+	assert!((pole_to_pole - 20015.09).abs() < 0.01);
+	assert!(north.distance_to(90.0, 123.0).abs() < 0.01);
+	assert!(!south.distance_to(90.0, 180.0).is_nan());
+
+	assert_eq!(north.sun_events(), None);
+	assert_eq!(south.sun_events(), None);
+
+	for &latitude in &[90.0, -90.0] {
+		let forecast: Forecast = serde_json::from_str(&format!(r#"{{
+			"daily": {{"data": [{{"time": 1509955200}}]}},
+			"latitude": {},
+			"longitude": 0,
+			"timezone": "Etc/UTC"
+		}}"#, latitude)).unwrap();
+
+		assert_eq!(forecast.sun_events(), None);
+	}
+
+	let degenerate: Forecast = serde_json::from_str(r#"{
+		"daily": {"data": [{"time": 1509955200, "sunriseTime": 1509990000, "sunsetTime": 1509960000}]},
+		"latitude": 89.5,
+		"longitude": 0,
+		"timezone": "Etc/UTC"
+	}"#).unwrap();
+	assert_eq!(degenerate.sun_events(), None);
+}
+
+fn key_paths(value: &serde_json::Value, path: &str, paths: &mut Vec<String>) {
+	match *value {
+		serde_json::Value::Array(ref items) => {
+			for item in items {
+				key_paths(item, &format!("{}[]", path), paths);
+			}
+		},
+		serde_json::Value::Object(ref map) => {
+			for (key, item) in map {
+				let path = format!("{}/{}", path, key);
+				key_paths(item, &path, paths);
+				paths.push(path);
+			}
+		},
+		_ => {},
+	}
+}
+
+#[test]
+fn test_serialize_uses_wire_names() {
+	let json = include_str!("fixtures/forecast.json");
+	let forecast = Forecast::from_json_str(json).unwrap();
+
+	let mut sent = vec![];
+	key_paths(&serde_json::from_str(json).unwrap(), "", &mut sent);
+	let mut serialized = vec![];
+	key_paths(&serde_json::to_value(&forecast).unwrap(), "", &mut serialized);
+
+	for path in &serialized {
+		assert!(sent.contains(path), "{} is not a name sent by the API", path);
+	}
+
+	assert!(serialized.iter().any(|path| path == "/currently/apparentTemperature"));
+	assert!(serialized.iter().any(|path| path == "/flags/isd-stations"));
+}
+
+#[test]
+fn test_serialize_round_trip_is_stable() {
+	let forecast = Forecast::from_json_str(include_str!("fixtures/forecast.json")).unwrap();
+
+	let first = serde_json::to_string(&forecast).unwrap();
+	let reparsed = Forecast::from_json_str(&first).unwrap();
+	let second = serde_json::to_string(&reparsed).unwrap();
+
+	assert_eq!(reparsed, forecast);
+	assert_eq!(first, second);
+}
@@ -0,0 +1,244 @@
+extern crate darksky;
+
+use darksky::*;
+
+#[test]
+fn test_build_uri() {
+	let options = Options::default()
+		.exclude(vec![Block::Currently, Block::Daily])
+		.language(Language::Es)
+		.unit(Unit::Si);
+
+	let uri = build_uri("token", 19.2465, -99.1013, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/19.2465,-99.1013?exclude=currently,daily&lang=es&units=si");
+}
+
+#[test]
+fn test_build_uri_catalan() {
+	let options = Options::default()
+		.language(Language::Ca)
+		.unit(Unit::Ca);
+
+	let uri = build_uri("token", 41.3874, 2.1686, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/41.3874,2.1686?lang=ca&units=ca");
+}
+
+#[test]
+fn test_build_uri_extend_hourly() {
+	let options = Options::default().extend_hourly();
+	let uri = build_uri("token", 37.8267, -122.423, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?extend=hourly");
+}
+
+#[test]
+fn test_build_uri_rejects_extend_with_hourly_excluded() {
+	let options = Options::default()
+		.exclude(vec![Block::Hourly, Block::Minutely])
+		.extend_hourly();
+
+	match build_uri("token", 37.8267, -122.423, &options) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("expected an invalid options error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_build_time_machine_uri_rejects_extend() {
+	let options = Options::default().extend_hourly();
+
+	match build_time_machine_uri("token", 37.8267, -122.423, 1509993277, &options) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("expected an invalid options error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_build_uri_trims_token() {
+	let options = Options::default();
+
+	for token in &[" token", "token ", "token\n", "\ttoken\r\n"] {
+		let uri = build_uri(token, 37.8267, -122.423, &options).unwrap();
+
+		assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423");
+	}
+}
+
+#[test]
+fn test_build_uri_missing_token() {
+	match build_uri(" \n", 37.8267, -122.423, &Options::default()) {
+		Err(Error::MissingToken) => {},
+		other => panic!("expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_only() {
+	let uri = |options: Options| build_uri("token", 37.8267, -122.423, &options).unwrap();
+
+	assert!(uri(Options::default().only(vec![Block::Currently])).ends_with("?exclude=alerts,daily,flags,hourly,minutely"));
+	assert!(uri(Options::default().only(vec![Block::Hourly, Block::Daily])).ends_with("?exclude=alerts,currently,flags,minutely"));
+	assert!(uri(Options::default().exclude(vec![Block::Hourly]).only(vec![Block::Hourly])).ends_with("?exclude=alerts,currently,daily,flags,minutely"));
+	assert!(uri(Options::default().only(vec![Block::Alerts, Block::Currently])).ends_with("?exclude=daily,flags,hourly,minutely"));
+	assert!(uri(Options::default().only(vec![Block::Currently]).exclude(vec![Block::Flags])).ends_with("?exclude=flags"));
+
+	let all = vec![Block::Alerts, Block::Currently, Block::Daily, Block::Flags, Block::Hourly, Block::Minutely];
+	assert!(uri(Options::default().exclude(vec![Block::Hourly]).only(all)).ends_with("-122.423"));
+}
+
+#[test]
+fn test_build_uri_invalid_location() {
+	for &(latitude, longitude) in &[(200.0, 0.0), (-90.5, 0.0), (0.0, -999.0), (0.0, 180.5), (f64::NAN, 0.0)] {
+		match build_uri("token", latitude, longitude, &Options::default()) {
+			Err(Error::InvalidLocation(..)) => {},
+			other => panic!("Expected an invalid location error for {}, {}, got {:?}", latitude, longitude, other),
+		}
+	}
+
+	assert!(build_uri("token", 90.0, -180.0, &Options::default()).is_ok());
+	assert!(build_time_machine_uri("token", -90.0, 180.0, 1509993277, &Options::default()).is_ok());
+}
+
+#[test]
+fn test_build_uri_encodes_values() {
+	let options = Options::default().custom("lang", "x-café ☃").unit(Unit::Si);
+	let uri = build_uri("token", 37.8267, -122.423, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?lang=x-caf%C3%A9%20%E2%98%83&units=si");
+}
+
+#[test]
+fn test_build_uri_encodes_keys_and_lists() {
+	let options = Options::default().custom("time zone", "a b,c&d");
+	let uri = build_uri("token", 37.8267, -122.423, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?time%20zone=a%20b,c%26d");
+}
+
+#[test]
+fn test_validate_token() {
+	assert!(validate_token("0123456789abcdef0123456789abcdef").is_ok());
+	assert!(validate_token("0123456789ABCDEF0123456789ABCDEF").is_ok());
+	assert!(validate_token(" 0123456789abcdef0123456789abcdef\n").is_ok());
+
+	for token in &["0123456789abcdef", "0123456789abcdef0123456789abcdef0", "0123456789abcdef0123456789abcdeg", "token"] {
+		match validate_token(token) {
+			Err(Error::MalformedToken) => {},
+			other => panic!("Expected a malformed token error for {:?}, got {:?}", token, other),
+		}
+	}
+
+	match validate_token(" ") {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_build_uri_default_units() {
+	let uri = build_uri("token", 37.8267, -122.423, &Options::default()).unwrap();
+	assert!(!uri.contains("units="));
+
+	let uri = build_uri("token", 37.8267, -122.423, &Options::default().unit(Unit::Auto)).unwrap();
+	assert!(uri.ends_with("?units=auto"));
+}
+
+#[test]
+fn test_build_probe_uri() {
+	let uri = build_probe_uri("token").unwrap();
+	let (_, query) = uri.split_at(uri.find("?exclude=").unwrap() + "?exclude=".len());
+	let excluded = query.split(',').collect::<Vec<_>>();
+
+	assert_eq!(excluded, vec!["alerts", "currently", "daily", "flags", "hourly", "minutely"]);
+
+	match build_probe_uri("") {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_options_merge() {
+	let base = Options::default()
+		.exclude(vec![Block::Minutely, Block::Flags])
+		.language(Language::De)
+		.unit(Unit::Us);
+	let overlay = Options::default()
+		.exclude(vec![Block::Flags, Block::Daily])
+		.unit(Unit::Si);
+
+	let uri = build_uri("token", 37.8267, -122.423, &base.clone().merge(overlay)).unwrap();
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=minutely,flags,daily&lang=de&units=si");
+
+	let uri = build_uri("token", 37.8267, -122.423, &base.merge(Options::default())).unwrap();
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=minutely,flags&lang=de&units=us");
+}
+
+#[test]
+fn test_options_merge_empty_exclude() {
+	let empty = Options::default().exclude(vec![]);
+	let overlay = Options::default().exclude(vec![Block::Flags]);
+
+	let uri = build_uri("token", 37.8267, -122.423, &empty.clone().merge(overlay.clone())).unwrap();
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=flags");
+
+	let uri = build_uri("token", 37.8267, -122.423, &overlay.merge(empty)).unwrap();
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=flags");
+}
+
+#[test]
+fn test_build_time_machine_uri() {
+	let options = Options::default().exclude(vec![Block::Minutely]).unit(Unit::Si);
+	let uri = build_time_machine_uri("token", 37.8267, -122.423, 1509993277, &options).unwrap();
+
+	assert_eq!(uri, "https://api.darksky.net/forecast/token/37.8267,-122.423,1509993277?exclude=minutely&units=si");
+
+	match build_time_machine_uri("", 37.8267, -122.423, 1509993277, &options) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_from_str() {
+	assert_eq!("si".parse::<Unit>().unwrap(), Unit::Si);
+	assert_eq!("UK2".parse::<Unit>().unwrap(), Unit::Uk2);
+	assert_eq!("zh-tw".parse::<Language>().unwrap(), Language::ZhTw);
+	assert_eq!("x-pig-latin".parse::<Language>().unwrap(), Language::XPigLatin);
+	assert_eq!("Alerts".parse::<Block>().unwrap(), Block::Alerts);
+
+	for unit in &[Unit::Auto, Unit::Ca, Unit::Si, Unit::Uk2, Unit::Us] {
+		assert_eq!(unit.to_string().parse::<Unit>().unwrap(), *unit);
+	}
+
+	for block in &[Block::Alerts, Block::Currently, Block::Daily, Block::Flags, Block::Hourly, Block::Minutely] {
+		assert_eq!(block.to_string().parse::<Block>().unwrap(), *block);
+	}
+
+	assert_eq!(Language::Ca.to_string(), "ca");
+
+	match "metric".parse::<Unit>() {
+		Err(Error::UnknownName { expected, name }) => {
+			assert_eq!(expected, "unit");
+			assert_eq!(name, "metric");
+		},
+		other => panic!("Expected an unknown name error, got {:?}", other),
+	}
+
+	let err = "klingon".parse::<Language>().unwrap_err();
+	assert_eq!(err.to_string(), r#"Unknown language: "klingon""#);
+}
+
+#[test]
+fn test_language_or_default() {
+	assert_eq!(Options::default().language_or_default(), Language::En);
+	assert_eq!(Options::default().language(Language::ZhTw).language_or_default(), Language::ZhTw);
+	assert_eq!(Options::default().custom("lang", "zz").language_or_default(), Language::En);
+
+	let merged = Options::default()
+		.language(Language::Fr)
+		.merge(Options::default().language(Language::Ca));
+	assert_eq!(merged.language_or_default(), Language::Ca);
+}
@@ -0,0 +1,20 @@
+#![cfg(feature="raw-json")]
+
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+#[test]
+fn test_raw_pointer() {
+	let body = include_str!("fixtures/forecast.json");
+	let raw = RawForecast::from_json_str(body).unwrap();
+
+	assert_eq!(raw.raw_pointer("/hourly/data/3/temperature"), Some(&serde_json::Value::from(56.2)));
+	assert_eq!(raw.raw_pointer("/latitude").and_then(|value| value.as_f64()), Some(37.8267));
+	assert_eq!(raw.raw_pointer("/hourly/data/99"), None);
+	assert_eq!(raw.forecast, Forecast::from_json_str(body).unwrap());
+
+	let raw = RawForecast::from_reader(body.as_bytes()).unwrap();
+	assert_eq!(raw.forecast.timezone, "America/Los_Angeles");
+}
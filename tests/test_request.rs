@@ -0,0 +1,71 @@
+extern crate darksky;
+
+use darksky::*;
+
+const TIME: u64 = 1509993277;
+
+#[test]
+fn test_request_uri() {
+	let request = Request::new("token")
+		.location(37.8267, -122.423)
+		.options(|o| o.unit(Unit::Si));
+
+	assert_eq!(request.uri().unwrap(), "https://api.darksky.net/forecast/token/37.8267,-122.423?units=si");
+
+	let time_machine = request.time(TIME);
+
+	assert_eq!(
+		time_machine.uri().unwrap(),
+		"https://api.darksky.net/forecast/token/37.8267,-122.423,1509993277?units=si",
+	);
+}
+
+#[test]
+fn test_request_send() {
+	let transport = SimulatedTransport::new(1).at(TIME);
+
+	let forecast = Request::new("token")
+		.location(37.8267, -122.423)
+		.send(&transport)
+		.unwrap();
+	let expected = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+	assert_eq!(forecast, expected);
+
+	let time_machine = Request::new("token")
+		.location(37.8267, -122.423)
+		.time(TIME)
+		.send(&transport)
+		.unwrap();
+	let expected = transport.get_time_machine("token", 37.8267, -122.423, TIME).unwrap();
+	assert_eq!(time_machine, expected);
+}
+
+#[test]
+fn test_request_validation() {
+	let transport = SimulatedTransport::new(1);
+
+	match Request::new("token").send(&transport) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("expected an invalid options error, got {:?}", other),
+	}
+
+	match Request::new(" ").location(37.8267, -122.423).send(&transport) {
+		Err(Error::MissingToken) => {},
+		other => panic!("expected a missing token error, got {:?}", other),
+	}
+
+	match Request::new("token").location(-122.423, 37.8267).send(&transport) {
+		Err(Error::InvalidLocation(..)) => {},
+		other => panic!("expected an invalid location error, got {:?}", other),
+	}
+
+	let extended = Request::new("token")
+		.location(37.8267, -122.423)
+		.options(|o| o.extend_hourly())
+		.time(TIME);
+
+	match extended.send(&transport) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("expected an invalid options error, got {:?}", other),
+	}
+}
@@ -0,0 +1,54 @@
+extern crate darksky;
+
+use darksky::*;
+
+/// A requester implementing only the required methods, serving the fixture
+/// forecast in place of the API.
+struct MinimalRequester;
+
+impl DarkskyRequester for MinimalRequester {
+	fn get_forecast(&self, token: &str, latitude: f64, longitude: f64) -> Result<Forecast> {
+		self.get_forecast_with_options(token, latitude, longitude, |o| o)
+	}
+
+	fn get_forecast_with_options<F>(&self, token: &str, latitude: f64, longitude: f64, options: F)
+		-> Result<Forecast> where F: FnOnce(Options) -> Options {
+		build_uri(token, latitude, longitude, &options(Options::default()))?;
+
+		Forecast::from_json_str(include_str!("fixtures/forecast.json"))
+	}
+}
+
+#[test]
+fn test_default_meta() {
+	let response = MinimalRequester.get_forecast_with_meta("token", 37.8267, -122.423, |o| o).unwrap();
+
+	assert_eq!(response.api_calls, None);
+	assert_eq!(response.cache_hit, None);
+	assert_eq!(response.response_time_ms, None);
+	assert_eq!(response.forecast.timezone, "America/Los_Angeles");
+}
+
+#[test]
+fn test_default_progress() {
+	let mut received = vec![];
+	MinimalRequester.get_forecast_with_progress("token", 37.8267, -122.423, |o| o, |bytes| {
+		received.push(bytes);
+	}).unwrap();
+
+	assert_eq!(received.len(), 1);
+	assert!(received[0] > 0);
+}
+
+#[test]
+fn test_default_time_machine() {
+	match MinimalRequester.get_time_machine("token", 37.8267, -122.423, 1509993277) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("Expected an invalid options error, got {:?}", other),
+	}
+
+	match MinimalRequester.get_time_machine(" ", 37.8267, -122.423, 1509993277) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
@@ -0,0 +1,19 @@
+#![cfg(feature="reqwest")]
+
+extern crate darksky;
+extern crate reqwest;
+
+use darksky::*;
+use std::task::{Context, Poll, Waker};
+
+#[test]
+fn test_get_forecast_missing_token() {
+	let client = reqwest::Client::new();
+	let mut future = client.get_forecast_with_options(" ", 37.8267, -122.423, |o| o);
+	let mut context = Context::from_waker(Waker::noop());
+
+	match future.as_mut().poll(&mut context) {
+		Poll::Ready(Err(Error::MissingToken)) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
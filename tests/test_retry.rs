@@ -0,0 +1,111 @@
+extern crate darksky;
+
+use darksky::*;
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+/// A transport serving each of the given responses in turn before serving
+/// the fixture forecast, counting its requests.
+struct FlakyTransport {
+	attempts: Cell<u32>,
+	responses: RefCell<Vec<TransportResponse>>,
+}
+
+impl FlakyTransport {
+	fn new(mut responses: Vec<TransportResponse>) -> FlakyTransport {
+		responses.reverse();
+
+		FlakyTransport {
+			attempts: Cell::new(0),
+			responses: RefCell::new(responses),
+		}
+	}
+}
+
+impl HttpTransport for FlakyTransport {
+	fn get(&self, _: &str) -> Result<TransportResponse> {
+		self.attempts.set(self.attempts.get() + 1);
+
+		Ok(self.responses.borrow_mut().pop().unwrap_or_else(|| {
+			TransportResponse::ok(include_str!("fixtures/forecast.json"))
+		}))
+	}
+}
+
+fn api(code: u16) -> TransportResponse {
+	TransportResponse {
+		body: format!(r#"{{"code":{},"error":"error"}}"#, code).into_bytes(),
+		headers: vec![],
+		status: code,
+	}
+}
+
+#[test]
+fn test_retry_transient() {
+	let transport = FlakyTransport::new(vec![api(503), api(500)]);
+	let start = Instant::now();
+
+	let forecast = transport.get_forecast_with_retry_delay("token", 37.8267, -122.423, 3, Duration::from_millis(5));
+
+	assert!(forecast.is_ok());
+	assert_eq!(transport.attempts.get(), 3);
+	// Backs off for 5ms, then 10ms.
+	assert!(start.elapsed() >= Duration::from_millis(15));
+}
+
+#[test]
+fn test_retry_maintenance_page() {
+	let maintenance = TransportResponse {
+		body: b"<html><body>Down for maintenance</body></html>".to_vec(),
+		headers: vec![("Content-Type".to_owned(), "text/html".to_owned())],
+		status: 503,
+	};
+
+	let transport = FlakyTransport::new(vec![maintenance.clone()]);
+
+	match transport.get_forecast("token", 37.8267, -122.423) {
+		Err(Error::Api { code: 503, .. }) => {},
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+
+	let transport = FlakyTransport::new(vec![maintenance]);
+
+	assert!(transport.get_forecast_with_retry_delay("token", 37.8267, -122.423, 1, Duration::from_millis(1)).is_ok());
+	assert_eq!(transport.attempts.get(), 2);
+}
+
+#[test]
+fn test_retry_gives_up() {
+	let transport = FlakyTransport::new(vec![api(502), api(503), api(504)]);
+
+	match transport.get_forecast_with_retry_delay("token", 37.8267, -122.423, 2, Duration::from_millis(1)) {
+		Err(Error::Api { code: 504, .. }) => {},
+		other => panic!("Expected the last API error, got {:?}", other),
+	}
+
+	assert_eq!(transport.attempts.get(), 3);
+}
+
+#[test]
+fn test_retry_not_transient() {
+	let transport = FlakyTransport::new(vec![api(403)]);
+
+	match transport.get_forecast_with_retry_delay("token", 37.8267, -122.423, 3, Duration::from_millis(1)) {
+		Err(Error::Api { code: 403, .. }) => {},
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+
+	assert_eq!(transport.attempts.get(), 1);
+
+	let transport = FlakyTransport::new(vec![]);
+	assert!(transport.get_forecast_with_retry("token", 200.0, 0.0, 3).is_err());
+	assert_eq!(transport.attempts.get(), 0);
+}
+
+#[test]
+fn test_retry_none() {
+	let transport = FlakyTransport::new(vec![api(500)]);
+
+	assert!(transport.get_forecast_with_retry("token", 37.8267, -122.423, 0).is_err());
+	assert_eq!(transport.attempts.get(), 1);
+}
@@ -0,0 +1,189 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+const TIME: u64 = 1509993277;
+
+fn data(block: &Option<Datablock>) -> &[Datapoint] {
+	block.as_ref().and_then(|block| block.data.as_ref()).map(|data| &data[..]).unwrap()
+}
+
+#[test]
+fn test_simulated_forecast_structure() {
+	let forecast = SimulatedTransport::new(1).at(TIME).get_forecast("token", 37.8267, -122.423).unwrap();
+
+	assert_eq!(forecast.latitude, 37.8267);
+	assert_eq!(forecast.longitude, -122.423);
+	assert_eq!(forecast.timezone, "Etc/GMT+8");
+	assert_eq!(forecast.offset, Some(-8.0));
+	assert!(forecast.alerts.is_empty());
+
+	let currently = forecast.currently.as_ref().unwrap();
+	assert_eq!(currently.time, TIME);
+	assert!(currently.icon.is_some());
+	assert!(currently.apparent_temperature.is_some());
+
+	let minutely = data(&forecast.minutely);
+	assert_eq!(minutely.len(), 61);
+	assert!(minutely.windows(2).all(|pair| pair[1].time - pair[0].time == 60));
+
+	let hourly = data(&forecast.hourly);
+	assert_eq!(hourly.len(), 49);
+	assert!(hourly.windows(2).all(|pair| pair[1].time - pair[0].time == 3600));
+
+	for datapoint in hourly {
+		let temperature = datapoint.temperature.unwrap();
+		assert!(temperature > -80.0 && temperature < 130.0);
+		assert!(datapoint.dew_point.unwrap() <= temperature);
+
+		for fraction in &[datapoint.cloud_cover, datapoint.humidity, datapoint.precip_probability] {
+			assert!(fraction.unwrap() >= 0.0 && fraction.unwrap() <= 1.0);
+		}
+
+		assert!(datapoint.precip_intensity.unwrap() >= 0.0);
+		assert!(datapoint.wind_gust.unwrap() >= datapoint.wind_speed.unwrap());
+		assert!(datapoint.wind_bearing.unwrap() >= 0.0 && datapoint.wind_bearing.unwrap() < 360.0);
+	}
+
+	let daily = data(&forecast.daily);
+	assert_eq!(daily.len(), 8);
+	assert!(daily.windows(2).all(|pair| pair[1].time - pair[0].time == 86400));
+
+	for datapoint in daily {
+		let sunrise = datapoint.sunrise_time.unwrap();
+		let sunset = datapoint.sunset_time.unwrap();
+		assert!(datapoint.time < sunrise && sunrise < sunset && sunset < datapoint.time + 86400);
+		assert!(datapoint.temperature_min.unwrap() <= datapoint.temperature_max.unwrap());
+		assert!(datapoint.moon_phase.unwrap() >= 0.0 && datapoint.moon_phase.unwrap() <= 1.0);
+	}
+
+	assert_eq!(forecast.flags.as_ref().and_then(|flags| flags.units.as_ref()).map(|units| &units[..]), Some("us"));
+}
+
+#[test]
+fn test_simulated_forecast_reproducible() {
+	let first = SimulatedTransport::new(7).at(TIME).get_forecast("token", 51.5, -0.12).unwrap();
+	let second = SimulatedTransport::new(7).at(TIME).get_forecast("token", 51.5, -0.12).unwrap();
+
+	assert_eq!(serde_json::to_string(&first).unwrap(), serde_json::to_string(&second).unwrap());
+}
+
+#[test]
+fn test_simulated_forecast_varies() {
+	let temperatures = |transport: SimulatedTransport, latitude: f64, longitude: f64| {
+		let forecast = transport.get_forecast("token", latitude, longitude).unwrap();
+
+		data(&forecast.hourly).iter().map(|datapoint| datapoint.temperature.unwrap()).collect::<Vec<_>>()
+	};
+
+	let base = temperatures(SimulatedTransport::new(1).at(TIME), 37.8267, -122.423);
+
+	assert_ne!(base, temperatures(SimulatedTransport::new(2).at(TIME), 37.8267, -122.423));
+	assert_ne!(base, temperatures(SimulatedTransport::new(1).at(TIME), 64.8, -147.7));
+	assert_ne!(base, temperatures(SimulatedTransport::new(1).at(TIME + 86400), 37.8267, -122.423));
+
+	let tropics = temperatures(SimulatedTransport::new(1).at(TIME), 1.35, 103.8);
+	let arctic = temperatures(SimulatedTransport::new(1).at(TIME), 78.2, 15.6);
+	let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+	assert!(mean(&tropics) > mean(&arctic));
+}
+
+#[test]
+fn test_simulated_forecast_options() {
+	let transport = SimulatedTransport::new(1).at(TIME);
+
+	let forecast = transport.get_forecast_with_options("token", 37.8267, -122.423, |o| o
+		.exclude(vec![Block::Minutely, Block::Flags])
+		.extend_hourly()
+		.unit(Unit::Si)).unwrap();
+
+	assert!(forecast.minutely.is_none());
+	assert!(forecast.flags.is_none());
+	assert_eq!(data(&forecast.hourly).len(), 169);
+
+	let us = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+	let fahrenheit = us.currently.and_then(|currently| currently.temperature).unwrap();
+	let celsius = forecast.currently.and_then(|currently| currently.temperature).unwrap();
+	assert!((celsius - (fahrenheit - 32.0) * 5.0 / 9.0).abs() < 0.01);
+}
+
+#[test]
+fn test_simulated_forecast_with_opts() {
+	let transport = SimulatedTransport::new(1).at(TIME);
+
+	let mut options = Options::new();
+	options = options.unit(Unit::Si);
+	options = options.exclude(vec![Block::Minutely]);
+
+	let prebuilt = transport.get_forecast_with_opts("token", 37.8267, -122.423, options).unwrap();
+	let closure = transport.get_forecast_with_options("token", 37.8267, -122.423, |o| o
+		.unit(Unit::Si)
+		.exclude(vec![Block::Minutely])).unwrap();
+
+	assert!(prebuilt.minutely.is_none());
+	assert_eq!(serde_json::to_string(&prebuilt).unwrap(), serde_json::to_string(&closure).unwrap());
+}
+
+#[test]
+fn test_simulated_forecast_polar() {
+	// Midsummer and midwinter at the north pole.
+	let summer = SimulatedTransport::new(1).at(1529582400).get_forecast("token", 89.9, 0.0).unwrap();
+	let winter = SimulatedTransport::new(1).at(1545393600).get_forecast("token", 89.9, 0.0).unwrap();
+
+	for forecast in &[summer, winter] {
+		let today = &data(&forecast.daily)[0];
+		assert!(today.sunrise_time.is_none());
+		assert!(today.sunset_time.is_none());
+	}
+}
+
+#[test]
+fn test_simulated_forecast_invalid_location() {
+	match SimulatedTransport::new(1).get_forecast("token", 91.0, 0.0) {
+		Err(Error::InvalidLocation(..)) => {},
+		other => panic!("Expected an invalid location error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_simulated_time_machine() {
+	let forecast = SimulatedTransport::new(1).get_time_machine("token", 37.8267, -122.423, TIME).unwrap();
+
+	assert_eq!(forecast.currently.as_ref().map(|currently| currently.time), Some(TIME));
+	assert!(forecast.minutely.is_none());
+	assert_eq!(data(&forecast.hourly).len(), 24);
+	assert_eq!(data(&forecast.daily).len(), 1);
+	assert_eq!(data(&forecast.daily)[0].time, data(&forecast.hourly)[0].time);
+}
+
+#[test]
+fn test_simulated_transport_responses() {
+	let transport = SimulatedTransport::new(1).at(TIME);
+
+	let uri = build_uri("token", 37.8267, -122.423, &Options::default().unit(Unit::Si)).unwrap();
+	let response = HttpTransport::get(&transport, &uri).unwrap();
+	assert_eq!(response.status, 200);
+	assert_eq!(response.header("content-type"), Some("application/json"));
+
+	let forecast: Forecast = serde_json::from_slice(&response.body).unwrap();
+	assert_eq!(forecast.flags.and_then(|flags| flags.units).as_ref().map(|units| &units[..]), Some("si"));
+
+	let response = HttpTransport::get(&transport, "https://example.com/").unwrap();
+	assert_eq!(response.status, 400);
+}
+
+#[test]
+fn test_simulated_transport_validates_requests() {
+	let transport = SimulatedTransport::new(1);
+
+	match transport.get_forecast(" ", 37.8267, -122.423) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+
+	match transport.get_time_machine_with_options("token", 37.8267, -122.423, TIME, |o| o.extend_hourly()) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("Expected an invalid options error, got {:?}", other),
+	}
+}
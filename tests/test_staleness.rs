@@ -0,0 +1,55 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A transport serving a forecast whose current datapoint is `age` old.
+struct AgedTransport {
+	age: u64,
+}
+
+impl HttpTransport for AgedTransport {
+	fn get(&self, _: &str) -> Result<TransportResponse> {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+		Ok(TransportResponse::ok(format!(r#"{{
+			"currently": {{"time": {}, "temperature": 58.3}},
+			"latitude": 37.8267,
+			"longitude": -122.423,
+			"timezone": "America/Los_Angeles"
+		}}"#, now - self.age)))
+	}
+}
+
+#[test]
+fn test_stale_data_rejected() {
+	let transport = AgedTransport { age: 6 * 60 * 60 };
+	let max_age = Some(Duration::from_secs(15 * 60));
+
+	match transport.get_forecast_with_max_age("token", 37.8267, -122.423, max_age, |o| o) {
+		Err(Error::StaleData { age }) => assert!(age >= Duration::from_secs(6 * 60 * 60)),
+		other => panic!("Expected stale data, got {:?}", other),
+	}
+
+	assert!(transport.get_forecast_with_max_age("token", 37.8267, -122.423, None, |o| o).is_ok());
+}
+
+#[test]
+fn test_fresh_data_accepted() {
+	let transport = AgedTransport { age: 60 };
+	let max_age = Some(Duration::from_secs(15 * 60));
+
+	assert!(transport.get_forecast_with_max_age("token", 37.8267, -122.423, max_age, |o| o).is_ok());
+}
+
+#[test]
+fn test_ensure_fresh_without_currently() {
+	let forecast: Forecast = serde_json::from_str(r#"{
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}"#).unwrap();
+
+	assert!(forecast.ensure_fresh(Duration::from_secs(0)).is_ok());
+}
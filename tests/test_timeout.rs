@@ -0,0 +1,133 @@
+extern crate darksky;
+
+#[cfg(feature="hyper")]
+extern crate hyper;
+#[cfg(feature="ureq")]
+extern crate ureq;
+
+use darksky::*;
+use std::io::{self, ErrorKind as IoErrorKind};
+use std::time::Duration;
+
+#[cfg(feature="ureq")]
+use std::net::TcpListener;
+#[cfg(feature="ureq")]
+use std::thread;
+#[cfg(feature="ureq")]
+use std::time::Instant;
+
+/// A transport serving an empty forecast, without support for timeouts.
+struct UnboundedTransport;
+
+impl HttpTransport for UnboundedTransport {
+	fn get(&self, _: &str) -> Result<TransportResponse> {
+		Ok(TransportResponse::ok(r#"{"latitude": 37.8267, "longitude": -122.423, "timezone": "America/Los_Angeles"}"#))
+	}
+}
+
+#[test]
+fn test_io_timeout_not_mapped() {
+	// Local IO, such as writing an archived forecast, is not a request
+	// timing out.
+	for kind in &[IoErrorKind::TimedOut, IoErrorKind::WouldBlock, IoErrorKind::ConnectionReset] {
+		match Error::from(io::Error::new(*kind, "failed")) {
+			Error::Io(_) => {},
+			other => panic!("Expected an io error, got {:?}", other),
+		}
+	}
+}
+
+#[test]
+fn test_get_forecast_with_timeout() {
+	let timeout = Duration::from_secs(1);
+
+	let forecast = SimulatedTransport::new(1).get_forecast_with_timeout("token", 37.8267, -122.423, timeout);
+	assert!(forecast.is_ok());
+
+	match UnboundedTransport.get_forecast_with_timeout("token", 37.8267, -122.423, timeout) {
+		Err(Error::InvalidOptions(_)) => {},
+		other => panic!("Expected an invalid options error, got {:?}", other),
+	}
+
+	match UnboundedTransport.get_forecast_with_timeout(" ", 37.8267, -122.423, timeout) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[cfg(feature="hyper")]
+#[test]
+fn test_hyper_timeout_mapped() {
+	let timeout = io::Error::new(IoErrorKind::WouldBlock, "timed out");
+
+	match Error::from(hyper::Error::Io(timeout)) {
+		Error::Timeout(None) => {},
+		other => panic!("Expected a timeout error, got {:?}", other),
+	}
+
+	match Error::from(hyper::Error::Status) {
+		Error::Hyper(_) => {},
+		other => panic!("Expected a hyper error, got {:?}", other),
+	}
+}
+
+#[cfg(feature="ureq")]
+#[test]
+fn test_ureq_timeout_mapped() {
+	let timeout = io::Error::new(IoErrorKind::TimedOut, "timed out");
+
+	match Error::from(ureq::Error::from(timeout)) {
+		Error::Timeout(None) => {},
+		other => panic!("Expected a timeout error, got {:?}", other),
+	}
+
+	let reset = io::Error::new(IoErrorKind::ConnectionReset, "reset");
+
+	match Error::from(ureq::Error::from(reset)) {
+		Error::Ureq(_) => {},
+		other => panic!("Expected a ureq error, got {:?}", other),
+	}
+}
+
+/// Accepts a connection on a local port but never responds, as a stalled
+/// server would, returning the URI to request.
+#[cfg(feature="ureq")]
+fn stall() -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let uri = format!("http://{}/", listener.local_addr().unwrap());
+
+	thread::spawn(move || {
+		let (_stream, _) = listener.accept().unwrap();
+		thread::sleep(Duration::from_secs(10));
+	});
+
+	uri
+}
+
+#[cfg(feature="ureq")]
+#[test]
+fn test_ureq_transport_timeout() {
+	let timeout = Duration::from_millis(100);
+	let start = Instant::now();
+
+	match HttpTransport::get(&UreqTransport::with_timeout(timeout), &stall()) {
+		Err(Error::Timeout(Some(actual))) => assert_eq!(actual, timeout),
+		other => panic!("Expected a timeout error, got {:?}", other),
+	}
+
+	assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[cfg(feature="ureq")]
+#[test]
+fn test_ureq_transport_per_call_timeout() {
+	let timeout = Duration::from_millis(100);
+	let start = Instant::now();
+
+	match UreqTransport::new().get_with_timeout(&stall(), timeout) {
+		Err(Error::Timeout(Some(actual))) => assert_eq!(actual, timeout),
+		other => panic!("Expected a timeout error, got {:?}", other),
+	}
+
+	assert!(start.elapsed() < Duration::from_secs(5));
+}
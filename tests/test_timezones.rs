@@ -0,0 +1,56 @@
+#![cfg(feature="timezones")]
+
+extern crate chrono_tz;
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+fn with_offset(time: u64, offset: f64) -> Forecast {
+	serde_json::from_str(&format!(r#"{{
+		"currently": {{"time": {}}},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"offset": {},
+		"timezone": "America/Los_Angeles"
+	}}"#, time, offset)).unwrap()
+}
+
+#[test]
+fn test_offset_consistency() {
+	// 2017-11-06, after the end of daylight saving time.
+	assert_eq!(with_offset(1509993277, -8.0).offset_consistency(), Some(true));
+	assert_eq!(with_offset(1509993277, -7.0).offset_consistency(), Some(false));
+
+	// 2017-07-01, during daylight saving time.
+	assert_eq!(with_offset(1498892400, -7.0).offset_consistency(), Some(true));
+	assert_eq!(with_offset(1498892400, -8.0).offset_consistency(), Some(false));
+
+	let fixture: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	assert_eq!(fixture.offset_consistency(), Some(true));
+}
+
+#[test]
+fn test_offset_consistency_unresolvable() {
+	let mut forecast = with_offset(1509993277, -8.0);
+	forecast.timezone = "Not/A_Zone".to_owned();
+	assert_eq!(forecast.offset_consistency(), None);
+
+	let mut forecast = with_offset(1509993277, -8.0);
+	forecast.offset = None;
+	assert_eq!(forecast.offset_consistency(), None);
+
+	let mut forecast = with_offset(1509993277, -8.0);
+	forecast.currently = None;
+	assert_eq!(forecast.offset_consistency(), None);
+}
+
+#[test]
+fn test_timezone_tz() {
+	let forecast = with_offset(1509993277, -8.0);
+	assert_eq!(forecast.timezone_tz(), Some(chrono_tz::America::Los_Angeles));
+
+	let mut forecast = forecast;
+	forecast.timezone = "Not/A_Zone".to_owned();
+	assert_eq!(forecast.timezone_tz(), None);
+}
@@ -0,0 +1,124 @@
+extern crate darksky;
+
+use darksky::*;
+use std::cell::RefCell;
+
+/// A transport serving a canned response, recording the requested URIs.
+struct FixtureTransport {
+	response: TransportResponse,
+	uris: RefCell<Vec<String>>,
+}
+
+impl FixtureTransport {
+	fn new(response: TransportResponse) -> FixtureTransport {
+		FixtureTransport {
+			response,
+			uris: RefCell::new(vec![]),
+		}
+	}
+}
+
+impl HttpTransport for FixtureTransport {
+	fn get(&self, uri: &str) -> Result<TransportResponse> {
+		self.uris.borrow_mut().push(uri.to_owned());
+
+		Ok(self.response.clone())
+	}
+}
+
+fn fixture() -> FixtureTransport {
+	let mut response = TransportResponse::ok(include_str!("fixtures/forecast.json"));
+	response.headers = vec![
+		("content-type".to_owned(), "application/json; charset=utf-8".to_owned()),
+		("X-Forecast-API-Calls".to_owned(), "42".to_owned()),
+		("x-forecast-cache".to_owned(), "miss".to_owned()),
+		("X-Response-Time".to_owned(), "55.612ms".to_owned()),
+	];
+
+	FixtureTransport::new(response)
+}
+
+#[test]
+fn test_transport_get_forecast() {
+	let transport = fixture();
+	let forecast = transport.get_forecast("token", 37.8267, -122.423).unwrap();
+
+	assert_eq!(forecast.timezone, "America/Los_Angeles");
+	assert_eq!(forecast.hourly_points().len(), 8);
+	assert_eq!(*transport.uris.borrow(), vec!["https://api.darksky.net/forecast/token/37.8267,-122.423"]);
+}
+
+#[test]
+fn test_transport_get_forecast_with_options() {
+	let transport = fixture();
+	transport.get_forecast_with_options("token", 37.8267, -122.423, |o| o
+		.exclude(vec![Block::Minutely])
+		.unit(Unit::Si)).unwrap();
+	transport.get_time_machine("token", 37.8267, -122.423, 1509993277).unwrap();
+
+	assert_eq!(*transport.uris.borrow(), vec![
+		"https://api.darksky.net/forecast/token/37.8267,-122.423?exclude=minutely&units=si",
+		"https://api.darksky.net/forecast/token/37.8267,-122.423,1509993277",
+	]);
+}
+
+#[test]
+fn test_transport_meta_and_progress() {
+	let transport = fixture();
+
+	let response = transport.get_forecast_with_meta("token", 37.8267, -122.423, |o| o).unwrap();
+	assert_eq!(response.api_calls, Some(42));
+	assert_eq!(response.cache_hit, Some(false));
+	assert_eq!(response.response_time_ms, Some(56));
+
+	let mut received = vec![];
+	transport.get_forecast_with_progress("token", 37.8267, -122.423, |o| o, |bytes| received.push(bytes)).unwrap();
+	assert_eq!(received, vec![include_str!("fixtures/forecast.json").len() as u64]);
+}
+
+#[test]
+fn test_transport_errors() {
+	let transport = FixtureTransport::new(TransportResponse {
+		body: br#"{"code":403,"error":"permission denied"}"#.to_vec(),
+		headers: vec![],
+		status: 403,
+	});
+
+	match transport.get_forecast("token", 37.8267, -122.423) {
+		Err(Error::Api { code: 403, ref message }) if message == "permission denied" => {},
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+
+	match transport.get_forecast(" ", 37.8267, -122.423) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+
+	assert_eq!(transport.uris.borrow().len(), 1);
+
+	let transport = FixtureTransport::new(TransportResponse::ok("[]"));
+
+	match transport.get_forecast("token", 37.8267, -122.423) {
+		Err(Error::UnexpectedResponse { ref body, status: 200, .. }) if body == "[]" => {},
+		other => panic!("Expected an unexpected response error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_transport_healthcheck() {
+	let transport = fixture();
+
+	assert!(transport.healthcheck("token").is_ok());
+	assert_eq!(*transport.uris.borrow(), vec![build_probe_uri("token").unwrap()]);
+
+	let transport = FixtureTransport::new(TransportResponse {
+		body: br#"{"code":403,"error":"permission denied"}"#.to_vec(),
+		headers: vec![],
+		status: 403,
+	});
+
+	match transport.healthcheck("token") {
+		Err(why @ Error::Api { code: 403, .. }) => assert_eq!(why.kind(), ErrorKind::Auth),
+		other => panic!("Expected an API error, got {:?}", other),
+	}
+}
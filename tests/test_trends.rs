@@ -0,0 +1,76 @@
+extern crate darksky;
+extern crate serde_json;
+
+use darksky::*;
+
+fn hourly(points: &[(f64, f64, f64, f64)]) -> Forecast {
+	let data = points.iter().enumerate().map(|(i, &(temperature, pressure, humidity, wind))| {
+		format!(
+			r#"{{"time": {}, "temperature": {}, "pressure": {}, "humidity": {}, "windSpeed": {}}}"#,
+			1509991200 + i * 3600, temperature, pressure, humidity, wind,
+		)
+	}).collect::<Vec<_>>().join(",");
+
+	serde_json::from_str(&format!(r#"{{
+		"hourly": {{"data": [{}]}},
+		"latitude": 37.8267,
+		"longitude": -122.423,
+		"timezone": "America/Los_Angeles"
+	}}"#, data)).unwrap()
+}
+
+#[test]
+fn test_trends_rising() {
+	let forecast = hourly(&[
+		(50.0, 1020.0, 0.90, 12.0),
+		(53.0, 1018.0, 0.80, 12.0),
+		(56.0, 1016.0, 0.70, 12.0),
+		(59.0, 1014.0, 0.60, 12.0),
+	]);
+
+	assert_eq!(forecast.trends(), Trends {
+		humidity: Some(Trend::Falling),
+		pressure: Some(Trend::Falling),
+		temperature: Some(Trend::Rising),
+		wind: Some(Trend::Steady),
+	});
+	assert_eq!(Trend::Rising.arrow(), "↑");
+}
+
+#[test]
+fn test_trends_flat() {
+	let forecast = hourly(&[
+		(50.0, 1016.0, 0.70, 5.0),
+		(50.2, 1016.1, 0.71, 5.1),
+		(49.9, 1015.9, 0.70, 4.9),
+		(50.1, 1016.0, 0.69, 5.0),
+	]);
+
+	assert_eq!(forecast.trends(), Trends {
+		humidity: Some(Trend::Steady),
+		pressure: Some(Trend::Steady),
+		temperature: Some(Trend::Steady),
+		wind: Some(Trend::Steady),
+	});
+	assert_eq!(Trend::Steady.arrow(), "→");
+}
+
+#[test]
+fn test_trends_insufficient_data() {
+	let forecast = hourly(&[(50.0, 1016.0, 0.70, 5.0)]);
+	assert_eq!(forecast.trends(), Trends::default());
+
+	let mut forecast = hourly(&[(50.0, 1016.0, 0.70, 5.0), (52.0, 1016.0, 0.70, 5.0)]);
+	forecast.hourly.as_mut().unwrap().data.as_mut().unwrap()[1].pressure = None;
+	assert_eq!(forecast.trends().temperature, Some(Trend::Rising));
+	assert_eq!(forecast.trends().pressure, None);
+}
+
+#[test]
+fn test_trends_from_currently() {
+	let forecast: Forecast = serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+	let trends = forecast.trends_within(2);
+
+	assert!(trends.temperature.is_some());
+	assert!(trends.humidity.is_some());
+}
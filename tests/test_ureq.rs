@@ -0,0 +1,65 @@
+#![cfg(feature="ureq")]
+
+extern crate darksky;
+
+use darksky::*;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+/// Serves a single canned HTTP response on a local port, returning the URI to
+/// request it from.
+fn serve(response: &'static str) -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let uri = format!("http://{}/", listener.local_addr().unwrap());
+
+	thread::spawn(move || {
+		let (mut stream, _) = listener.accept().unwrap();
+		let mut request = [0; 1024];
+		let _ = stream.read(&mut request);
+		stream.write_all(response.as_bytes()).unwrap();
+	});
+
+	uri
+}
+
+#[test]
+fn test_get_forecast_missing_token() {
+	match UreqTransport::new().get_forecast(" ", 37.8267, -122.423) {
+		Err(Error::MissingToken) => {},
+		other => panic!("Expected a missing token error, got {:?}", other),
+	}
+}
+
+#[test]
+fn test_get_response() {
+	let uri = serve("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nX-Forecast-API-Calls: 12\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}");
+	let response = HttpTransport::get(&UreqTransport::new(), &uri).unwrap();
+
+	assert_eq!(response.status, 200);
+	assert_eq!(response.header("content-type"), Some("application/json"));
+	assert_eq!(response.header("X-Forecast-API-Calls"), Some("12"));
+	assert_eq!(response.body, b"{}");
+}
+
+#[test]
+fn test_get_unsuccessful_response() {
+	let uri = serve("HTTP/1.1 503 Service Unavailable\r\nContent-Length: 4\r\nConnection: close\r\n\r\nBusy");
+	let response = HttpTransport::get(&UreqTransport::new(), &uri).unwrap();
+
+	assert_eq!(response.status, 503);
+	assert_eq!(response.body, b"Busy");
+}
+
+#[test]
+fn test_get_connection_refused() {
+	let uri = {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		format!("http://{}/", listener.local_addr().unwrap())
+	};
+
+	let err = HttpTransport::get(&UreqTransport::new(), &uri).unwrap_err();
+
+	assert_eq!(err.kind(), ErrorKind::Network);
+	assert!(err.is_transient());
+}